@@ -9,6 +9,10 @@ use crossterm::event::{
     MouseEvent,
 };
 use turi::{
+    backend::{
+        BufferedBackend,
+        CrosstermBackend,
+    },
     executor,
     orientation::Orientation,
     vec2::Vec2,
@@ -16,11 +20,8 @@ use turi::{
     views::TextView,
 };
 
-#[bench]
-fn crossterm_scroll_bench(b: &mut test::Bencher) {
-    let mut buf = Vec::with_capacity(1024 * 1024);
+fn scroll_events() -> Vec<Event> {
     let mut events = Vec::with_capacity(1024);
-    let mut view = TextView::new("1234567890".repeat(10)).scrollable(Orientation::Horizontal);
 
     for _ in 0..512 {
         events.push(Event::Mouse(MouseEvent::Down(
@@ -37,8 +38,30 @@ fn crossterm_scroll_bench(b: &mut test::Bencher) {
         )));
     }
 
+    events
+}
+
+#[bench]
+fn crossterm_scroll_bench(b: &mut test::Bencher) {
+    let mut buf = Vec::with_capacity(1024 * 1024);
+    let events = scroll_events();
+    let mut view = TextView::new("1234567890".repeat(10)).scrollable(Orientation::Horizontal);
+
+    b.iter(|| {
+        let mut backend = CrosstermBackend::new(&mut buf, Vec2::new(40, 10));
+        executor::bench(&mut backend, &mut view, events.iter().copied());
+        buf.clear();
+    });
+}
+
+#[bench]
+fn buffered_scroll_bench(b: &mut test::Bencher) {
+    let mut buf = Vec::with_capacity(1024 * 1024);
+    let events = scroll_events();
+    let mut view = TextView::new("1234567890".repeat(10)).scrollable(Orientation::Horizontal);
+
     b.iter(|| {
-        let mut backend = turi::backend::CrosstermBackend::new(&mut buf, Vec2::new(40, 10));
+        let mut backend = BufferedBackend::new(CrosstermBackend::new(&mut buf, Vec2::new(40, 10)));
         executor::bench(&mut backend, &mut view, events.iter().copied());
         buf.clear();
     });
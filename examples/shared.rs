@@ -1,17 +1,14 @@
-use crossterm::event::{
-    Event,
-    KeyCode,
-    KeyEvent,
-    KeyModifiers,
-};
 use simplelog::*;
 use std::io::BufWriter;
 use turi::{
     backend::{
+        BufferedBackend,
         CrosstermBackend,
         CrosstermBackendGuard,
     },
+    event::Event,
     executor,
+    keymap::KeyMap,
     state::RedrawState,
     style::Theme,
     view::View,
@@ -33,30 +30,23 @@ pub fn run<S: RedrawState>(
 
     let backend = CrosstermBackend::new(out, crossterm::terminal::size().unwrap().into());
     let mut guard = CrosstermBackendGuard::new(backend);
+    let mut backend = BufferedBackend::new(guard.inner());
 
     let theme = Theme::default();
 
-    let mut view = view.or_else_first(|_view, _state, event: Event| {
-        match event {
-            Event::Key(KeyEvent {
-                code: KeyCode::Char('c'),
-                modifiers: KeyModifiers::CONTROL,
-            }) => Some(true),
-            _ => None,
-        }
-    });
+    let mut view = view.keymap(KeyMap::new().bind("ctrl-c", true));
 
     executor::simple(
         &mut state,
-        guard.inner(),
+        &mut backend,
         &theme,
         &mut view,
         |state, backend| {
             loop {
-                match crossterm::event::read().unwrap() {
-                    Event::Resize(x, y) => {
+                match crossterm::event::read().unwrap().into() {
+                    Event::Resize(size) => {
                         state.set_need_redraw(true);
-                        backend.resize((x, y).into());
+                        backend.inner_mut().resize(size);
                     }
                     e => break e,
                 }
@@ -0,0 +1,378 @@
+//! Conversion of terminal output with ANSI escape sequences into [`StyledText`]
+//!
+//! SGR sequences(`ESC [ ... m`) are mapped to [`Style`], including 256 colors(`38;5;n`) and
+//! RGB(`38;2;r;g;b`). Other escape sequences and control characters except `\n` are removed,
+//! `\r\n` becomes a single line break.
+//!
+//! A lone `\r` returns to the start of the line so text after it replaces the line, like a
+//! progress bar redrawing itself. Text returned for earlier chunks can't be taken back so a `\r` at
+//! the start of a chunk only clears what follows it in that chunk.
+//!
+//! A sequence broken by a control character or longer than [`MAX_SEQUENCE_LEN`] is dropped up to
+//! that point and the rest is kept as text, so a stray `ESC` cannot swallow later output.
+
+use crate::{
+    style::{
+        AnsiColor,
+        Color,
+        Effect,
+        Style,
+    },
+    styled_text::StyledText,
+};
+use std::mem;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Longest unfinished escape sequence kept between chunks
+pub const MAX_SEQUENCE_LEN: usize = 256;
+
+/// Stateful parser, style set by a sequence lasts until it is changed so output can be parsed in
+/// chunks
+#[derive(Clone, Debug, Default)]
+pub struct AnsiParser {
+    style:   Style,
+    /// Escape sequence or `\r` cut at the end of last chunk
+    pending: String,
+}
+
+impl AnsiParser {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current style set by SGR sequences
+    #[inline]
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.style = Style::default();
+        self.pending.clear();
+    }
+
+    pub fn parse(
+        &mut self,
+        text: &str,
+    ) -> StyledText {
+        let input = mem::take(&mut self.pending) + text;
+        let mut out = StyledText::new();
+        let mut buf = String::new();
+        let mut pos = 0;
+
+        while let Some(ch) = input[pos..].chars().next() {
+            match ch {
+                '\x1b' => {
+                    let len = match escape_len(&input.as_bytes()[pos..]) {
+                        Some(len) => len,
+                        // Too long to be a real sequence, drop `ESC` and keep the rest as text
+                        None if input.len() - pos > MAX_SEQUENCE_LEN => 1,
+                        None => {
+                            self.pending.push_str(&input[pos..]);
+                            break;
+                        }
+                    };
+
+                    let seq = &input[pos..pos + len];
+
+                    if let Some(params) =
+                        seq.strip_prefix("\x1b[").and_then(|s| s.strip_suffix('m'))
+                    {
+                        out.append_styled(&buf, self.style);
+                        buf.clear();
+                        self.apply_sgr(params);
+                    }
+
+                    pos += len;
+                }
+                '\n' => {
+                    buf.push('\n');
+                    pos += 1;
+                }
+                '\r' if input[pos + 1..].starts_with('\n') => {
+                    buf.push('\n');
+                    pos += 2;
+                }
+                // Wait for next chunk to tell a line break from a lone `\r`
+                '\r' if pos + 1 == input.len() => {
+                    self.pending.push('\r');
+                    break;
+                }
+                '\r' => {
+                    out.append_styled(&buf, self.style);
+                    buf.clear();
+
+                    let start = out.source().rfind('\n').map_or(0, |i| i + 1);
+                    out = out.sub(0..start);
+                    pos += 1;
+                }
+                '\t' => {
+                    buf.push_str("    ");
+                    pos += 1;
+                }
+                ch => {
+                    if !ch.is_control() {
+                        buf.push(ch);
+                    }
+                    pos += ch.len_utf8();
+                }
+            }
+        }
+
+        out.append_styled(&buf, self.style);
+        out
+    }
+
+    fn apply_sgr(
+        &mut self,
+        params: &str,
+    ) {
+        // Empty parameter means `0`, one that is not a number is skipped
+        let mut codes = params.split([';', ':']).filter_map(|code| {
+            if code.is_empty() {
+                Some(0)
+            } else {
+                code.parse::<u16>().ok()
+            }
+        });
+
+        while let Some(code) = codes.next() {
+            let style = &mut self.style;
+
+            match code {
+                0 => *style = Style::default(),
+                1 => style.effects |= Effect::Bold,
+                2 => style.effects |= Effect::Dim,
+                3 => style.effects |= Effect::Italic,
+                4 => style.effects |= Effect::Underline,
+                5 | 6 => style.effects |= Effect::Blink,
+                7 => style.effects |= Effect::Reverse,
+                8 => style.effects |= Effect::Hidden,
+                9 => style.effects |= Effect::StrikeThrough,
+                21 | 22 => style.effects -= Effect::Bold | Effect::Dim,
+                23 => style.effects -= Effect::Italic,
+                24 => style.effects -= Effect::Underline,
+                25 => style.effects -= Effect::Blink,
+                27 => style.effects -= Effect::Reverse,
+                28 => style.effects -= Effect::Hidden,
+                29 => style.effects -= Effect::StrikeThrough,
+                30..=37 => style.fg = basic_color(code - 30),
+                38 => {
+                    if let Some(color) = extended_color(&mut codes) {
+                        style.fg = color;
+                    }
+                }
+                39 => style.fg = Color::default(),
+                40..=47 => style.bg = basic_color(code - 40),
+                48 => {
+                    if let Some(color) = extended_color(&mut codes) {
+                        style.bg = color;
+                    }
+                }
+                49 => style.bg = Color::default(),
+                90..=97 => style.fg = Color::Ansi(Some(AnsiColor::Fixed((code - 90 + 8) as u8))),
+                100..=107 => style.bg = Color::Ansi(Some(AnsiColor::Fixed((code - 100 + 8) as u8))),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn basic_color(n: u16) -> Color {
+    let color = match n {
+        0 => AnsiColor::Black,
+        1 => AnsiColor::Red,
+        2 => AnsiColor::Green,
+        3 => AnsiColor::Yellow,
+        4 => AnsiColor::Blue,
+        5 => AnsiColor::Purple,
+        6 => AnsiColor::Cyan,
+        _ => AnsiColor::White,
+    };
+
+    Color::Ansi(Some(color))
+}
+
+/// Parameters after `38` or `48`: `5;n` or `2;r;g;b`
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    let mut next = || codes.next().map(|code| code.min(255) as u8);
+
+    let color = match next()? {
+        5 => AnsiColor::Fixed(next()?),
+        2 => AnsiColor::RGB(next()?, next()?, next()?),
+        _ => return None,
+    };
+
+    Some(Color::Ansi(Some(color)))
+}
+
+/// Length of escape sequence at the start of `bytes`, `None` when it is not finished
+///
+/// A sequence broken by a byte which is not valid in it ends before that byte.
+fn escape_len(bytes: &[u8]) -> Option<usize> {
+    debug_assert_eq!(bytes[0], ESC);
+
+    match *bytes.get(1)? {
+        // CSI: parameter and intermediate bytes then a final byte
+        b'[' => {
+            for (i, &b) in bytes.iter().enumerate().skip(2) {
+                match b {
+                    0x40..=0x7e => return Some(i + 1),
+                    0x20..=0x3f => {}
+                    // Malformed, drop what is read so far
+                    _ => return Some(i),
+                }
+            }
+
+            None
+        }
+        // OSC and other strings: terminated by BEL or ST(`ESC \`)
+        b']' | b'P' | b'X' | b'^' | b'_' => {
+            for (i, &b) in bytes.iter().enumerate().skip(2) {
+                match b {
+                    BEL => return Some(i + 1),
+                    ESC => {
+                        return match bytes.get(i + 1)? {
+                            b'\\' => Some(i + 2),
+                            _ => Some(i),
+                        };
+                    }
+                    // Malformed, e.g. `\n` of unterminated title
+                    0x00..=0x1f | 0x7f => return Some(i),
+                    _ => {}
+                }
+            }
+
+            None
+        }
+        // Intermediate bytes then a final byte
+        _ => {
+            for (i, &b) in bytes.iter().enumerate().skip(1) {
+                match b {
+                    0x20..=0x2f => {}
+                    0x30..=0x7e => return Some(i + 1),
+                    _ => return Some(i),
+                }
+            }
+
+            None
+        }
+    }
+}
+
+#[test]
+fn ansi_sgr_test() {
+    let red_bold = Style::default()
+        .fg(Color::Ansi(Some(AnsiColor::Red)))
+        .effects(Effect::Bold);
+
+    assert_eq!(
+        AnsiParser::new().parse("a\x1b[1;31mb\x1b[22mc\x1b[0md\x1b[mE"),
+        StyledText::plain("a")
+            .span("b", red_bold)
+            .span("c", Style::default().fg(Color::Ansi(Some(AnsiColor::Red))))
+            .span("dE", Style::default())
+    );
+}
+
+#[test]
+fn ansi_extended_color_test() {
+    assert_eq!(
+        AnsiParser::new().parse("\x1b[38;5;208ma\x1b[48;2;1;2;3;39mb\x1b[92mc"),
+        StyledText::new()
+            .span(
+                "a",
+                Style::default().fg(Color::Ansi(Some(AnsiColor::Fixed(208))))
+            )
+            .span(
+                "b",
+                Style::default().bg(Color::Ansi(Some(AnsiColor::RGB(1, 2, 3))))
+            )
+            .span(
+                "c",
+                Style::default()
+                    .fg(Color::Ansi(Some(AnsiColor::Fixed(10))))
+                    .bg(Color::Ansi(Some(AnsiColor::RGB(1, 2, 3))))
+            )
+    );
+}
+
+#[test]
+fn ansi_strip_test() {
+    assert_eq!(
+        AnsiParser::new().parse("a\x1b[2K\x1b]0;title\x07b\x1b]8;;url\x1b\\c\x1b(Bd\r\n\x07e"),
+        StyledText::plain("abcd\ne")
+    );
+}
+
+#[test]
+fn ansi_carriage_return_test() {
+    let mut parser = AnsiParser::new();
+
+    assert_eq!(parser.parse("a\r\nb\r"), StyledText::plain("a\nb"));
+    assert_eq!(parser.parse("\nc\n"), StyledText::plain("\nc\n"));
+    assert_eq!(parser.parse("\nd"), StyledText::plain("\nd"));
+}
+
+#[test]
+fn ansi_lone_carriage_return_test() {
+    let mut parser = AnsiParser::new();
+    let red = Style::default().fg(Color::Ansi(Some(AnsiColor::Red)));
+
+    assert_eq!(
+        parser.parse("a\n10%\r\x1b[31m20%\x1b[0m\rdone\n"),
+        StyledText::plain("a\ndone\n")
+    );
+    assert_eq!(
+        parser.parse("x\n\x1b[31m1\r2"),
+        StyledText::plain("x\n").span("2", red)
+    );
+
+    // Text before a `\r` at the end of a chunk is already returned
+    assert_eq!(parser.parse("\x1b[0m3\r"), StyledText::plain("3"));
+    assert_eq!(parser.parse("4"), StyledText::plain("4"));
+}
+
+#[test]
+fn ansi_unterminated_test() {
+    let mut parser = AnsiParser::new();
+
+    assert_eq!(parser.parse("a\x1b]0;title"), StyledText::plain("a"));
+    assert_eq!(parser.parse("\nb"), StyledText::plain("\nb"));
+
+    assert_eq!(parser.parse("\x1b]"), StyledText::new());
+    let long = "x".repeat(MAX_SEQUENCE_LEN);
+    assert_eq!(parser.parse(&long), StyledText::plain(format!("]{}", long)));
+    assert_eq!(parser.parse("c"), StyledText::plain("c"));
+}
+
+#[test]
+fn ansi_invalid_param_test() {
+    assert_eq!(
+        AnsiParser::new().parse("\x1b[1ma\x1b[31;1<2;99999mb\x1b[;3mc"),
+        StyledText::new()
+            .span("a", Style::default().effects(Effect::Bold))
+            .span(
+                "b",
+                Style::default()
+                    .fg(Color::Ansi(Some(AnsiColor::Red)))
+                    .effects(Effect::Bold)
+            )
+            .span("c", Style::default().effects(Effect::Italic))
+    );
+}
+
+#[test]
+fn ansi_chunk_test() {
+    let mut parser = AnsiParser::new();
+    let red = Style::default().fg(Color::Ansi(Some(AnsiColor::Red)));
+
+    assert_eq!(parser.parse("a\x1b[3"), StyledText::plain("a"));
+    assert_eq!(parser.parse("1mb"), StyledText::styled("b", red));
+    assert_eq!(parser.parse("c"), StyledText::styled("c", red));
+    assert_eq!(parser.style(), red);
+}
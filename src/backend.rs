@@ -4,12 +4,17 @@ use ansi_term::Style;
 #[cfg(feature = "crossterm-backend")]
 mod crossterm;
 
+mod buffered;
+
 mod dummy;
 
+#[cfg(feature = "test-backend")]
 mod test;
 
 mod sliced;
 
+mod dimmed;
+
 #[cfg(feature = "crossterm-backend")]
 pub use self::crossterm::{
     CrosstermBackend,
@@ -19,6 +24,8 @@ pub use self::crossterm::{
 #[cfg(feature = "test-backend")]
 pub use self::test::TestBackend;
 pub use self::{
+    buffered::BufferedBackend,
+    dimmed::DimmedBackend,
     dummy::DummyBackend,
     sliced::SlicedBackend,
 };
@@ -39,7 +46,7 @@ pub trait Backend {
     fn flush(&mut self);
 }
 
-impl<'a, B: Backend> Backend for &'a mut B {
+impl<B: Backend> Backend for &mut B {
     #[inline]
     fn clear(&mut self) {
         (**self).clear();
@@ -0,0 +1,486 @@
+use crate::{
+    backend::Backend,
+    style::AnsiStyle as Style,
+    vec2::Vec2,
+};
+use std::mem::swap;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Right half of a wide character
+const CONTINUATION: char = '\0';
+
+#[derive(Clone, Debug, PartialEq)]
+struct Cell {
+    /// First char of the grapheme
+    symbol: char,
+    /// Rest of the grapheme e.g. combining marks or ZWJ sequences
+    extra:  Option<Box<str>>,
+    style:  Style,
+}
+
+impl Cell {
+    const BLANK: Cell = Cell {
+        symbol: ' ',
+        extra:  None,
+        style:  Style {
+            foreground:       None,
+            background:       None,
+            is_bold:          false,
+            is_dimmed:        false,
+            is_italic:        false,
+            is_underline:     false,
+            is_blink:         false,
+            is_reverse:       false,
+            is_hidden:        false,
+            is_strikethrough: false,
+        },
+    };
+
+    fn new(
+        grapheme: &str,
+        style: Style,
+    ) -> Self {
+        let mut chars = grapheme.chars();
+        let symbol = chars.next().unwrap_or(' ');
+        let rest = chars.as_str();
+
+        Self {
+            symbol,
+            extra: if rest.is_empty() {
+                None
+            } else {
+                Some(rest.into())
+            },
+            style,
+        }
+    }
+
+    #[inline]
+    fn is_continuation(&self) -> bool {
+        self.symbol == CONTINUATION
+    }
+
+    /// Replace symbol with a space but keep the style
+    #[inline]
+    fn erase(&mut self) {
+        self.symbol = ' ';
+        self.extra = None;
+    }
+
+    fn append(
+        &mut self,
+        text: &str,
+    ) {
+        let mut extra = self.extra.take().map(String::from).unwrap_or_default();
+        extra.push_str(text);
+        self.extra = Some(extra.into());
+    }
+
+    #[inline]
+    fn push_to(
+        &self,
+        out: &mut String,
+    ) {
+        out.push(self.symbol);
+        if let Some(extra) = &self.extra {
+            out.push_str(extra);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Buffer {
+    size:  Vec2,
+    cells: Vec<Cell>,
+}
+
+impl Buffer {
+    fn new(size: Vec2) -> Self {
+        Self {
+            size,
+            cells: vec![Cell::BLANK; size.x as usize * size.y as usize],
+        }
+    }
+
+    fn resize(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+        self.cells.clear();
+        self.cells
+            .resize(size.x as usize * size.y as usize, Cell::BLANK);
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        for cell in self.cells.iter_mut() {
+            *cell = Cell::BLANK;
+        }
+    }
+
+    #[inline]
+    fn row(
+        &self,
+        y: u16,
+    ) -> &[Cell] {
+        let w = self.size.x as usize;
+        let start = y as usize * w;
+        &self.cells[start..start + w]
+    }
+
+    fn put_str(
+        &mut self,
+        pos: Vec2,
+        text: &str,
+        style: Style,
+    ) {
+        if pos.y >= self.size.y {
+            return;
+        }
+
+        let w = self.size.x as usize;
+        let start = pos.y as usize * w;
+        let row = &mut self.cells[start..start + w];
+        let mut x = pos.x as usize;
+
+        for grapheme in text.graphemes(true) {
+            let width = grapheme.width().min(2);
+
+            if width == 0 {
+                // Marks printed on their own belong to the grapheme before them
+                let base = row[..x.min(w)]
+                    .iter()
+                    .rposition(|cell| !cell.is_continuation());
+
+                if let Some(base) = base {
+                    if !grapheme.chars().any(char::is_control) {
+                        row[base].append(grapheme);
+                    }
+                }
+                continue;
+            }
+
+            if x + width > w {
+                break;
+            }
+
+            // Break wide characters that are partially overwritten
+            if row[x].is_continuation() && x > 0 {
+                row[x - 1].erase();
+            }
+            let next = x + width;
+            if next < w && row[next].is_continuation() {
+                row[next].erase();
+            }
+
+            row[x] = Cell::new(grapheme, style);
+            if width == 2 {
+                row[x + 1] = Cell {
+                    symbol: CONTINUATION,
+                    extra: None,
+                    style,
+                };
+            }
+
+            x += width;
+        }
+    }
+}
+
+/// Double-buffered wrapper around another [`Backend`]
+///
+/// Every print goes into a back buffer of cells, `flush` compares it with what was sent last
+/// time and only emits the changed runs to the inner backend.
+/// `clear` only resets the back buffer so it doesn't cause flickering.
+pub struct BufferedBackend<B> {
+    inner:       B,
+    front:       Buffer,
+    back:        Buffer,
+    style:       Style,
+    invalidated: bool,
+    run:         String,
+}
+
+impl<B: Backend> BufferedBackend<B> {
+    pub fn new(inner: B) -> Self {
+        let size = inner.size();
+        Self {
+            inner,
+            front: Buffer::new(size),
+            back: Buffer::new(size),
+            style: Style::new(),
+            invalidated: true,
+            run: String::with_capacity(256),
+        }
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Force next `flush` to redraw whole screen
+    #[inline]
+    pub fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
+
+    fn sync_size(&mut self) {
+        let size = self.inner.size();
+
+        if size != self.back.size {
+            self.front.resize(size);
+            self.back.resize(size);
+            self.invalidated = true;
+        }
+    }
+
+    fn emit_run(
+        &mut self,
+        y: u16,
+        start: usize,
+        end: usize,
+    ) {
+        self.run.clear();
+
+        let row = self.back.row(y);
+        let mut style = row[start].style;
+        let mut run_start = start;
+
+        for (x, cell) in row.iter().enumerate().take(end).skip(start) {
+            if cell.is_continuation() {
+                continue;
+            }
+
+            if cell.style != style {
+                self.inner.set_style(style);
+                self.inner
+                    .print_at(Vec2::new(run_start as u16, y), &self.run);
+                self.run.clear();
+                style = cell.style;
+                run_start = x;
+            }
+
+            cell.push_to(&mut self.run);
+        }
+
+        if !self.run.is_empty() {
+            self.inner.set_style(style);
+            self.inner
+                .print_at(Vec2::new(run_start as u16, y), &self.run);
+        }
+    }
+}
+
+impl<B: Backend> Backend for BufferedBackend<B> {
+    #[inline]
+    fn clear(&mut self) {
+        self.sync_size();
+        self.back.clear();
+    }
+
+    #[inline]
+    fn size(&self) -> Vec2 {
+        self.inner.size()
+    }
+
+    #[inline]
+    fn set_style(
+        &mut self,
+        style: Style,
+    ) {
+        self.style = style;
+    }
+
+    #[inline]
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    #[inline]
+    fn print_at(
+        &mut self,
+        pos: Vec2,
+        text: &str,
+    ) {
+        self.back.put_str(pos, text, self.style);
+    }
+
+    fn flush(&mut self) {
+        self.sync_size();
+
+        if self.invalidated {
+            self.inner.set_style(Style::new());
+            self.inner.clear();
+            self.front.clear();
+            self.invalidated = false;
+        }
+
+        let w = self.back.size.x as usize;
+
+        for y in 0..self.back.size.y {
+            let mut x = 0;
+
+            while x < w {
+                if self.back.row(y)[x] == self.front.row(y)[x] {
+                    x += 1;
+                    continue;
+                }
+
+                // Always start from left half of wide character
+                let start = if self.back.row(y)[x].is_continuation() && x > 0 {
+                    x - 1
+                } else {
+                    x
+                };
+
+                let mut end = x + 1;
+                while end < w && self.back.row(y)[end] != self.front.row(y)[end] {
+                    end += 1;
+                }
+
+                // Don't split wide character at the end of the run
+                if end < w && self.back.row(y)[end].is_continuation() {
+                    end += 1;
+                }
+
+                self.emit_run(y, start, end);
+                x = end;
+            }
+        }
+
+        swap(&mut self.front, &mut self.back);
+        self.back.cells.clone_from_slice(&self.front.cells);
+
+        self.inner.set_style(self.style);
+        self.inner.flush();
+    }
+}
+
+#[cfg(all(test, feature = "test-backend"))]
+struct Recorder {
+    inner:  crate::backend::TestBackend,
+    prints: Vec<(Vec2, String)>,
+}
+
+#[cfg(all(test, feature = "test-backend"))]
+impl Backend for Recorder {
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    fn size(&self) -> Vec2 {
+        self.inner.size()
+    }
+
+    fn set_style(
+        &mut self,
+        style: Style,
+    ) {
+        self.inner.set_style(style);
+    }
+
+    fn style(&self) -> Style {
+        self.inner.style()
+    }
+
+    fn print_at(
+        &mut self,
+        pos: Vec2,
+        text: &str,
+    ) {
+        self.prints.push((pos, text.into()));
+        self.inner.print_at(pos, text);
+    }
+
+    fn flush(&mut self) {}
+}
+
+#[cfg(feature = "test-backend")]
+#[test]
+fn buffered_backend_only_flush_changed() {
+    use crate::backend::TestBackend;
+
+    let mut backend = BufferedBackend::new(Recorder {
+        inner:  TestBackend::new(Vec2::new(10, 3)),
+        prints: Vec::new(),
+    });
+
+    backend.clear();
+    backend.print_at(Vec2::new(0, 0), "ABCDEF");
+    backend.print_at(Vec2::new(2, 1), "가나다");
+    backend.flush();
+
+    assert_eq!(backend.inner().prints, &[
+        (Vec2::new(0, 0), "ABCDEF".into()),
+        (Vec2::new(2, 1), "가나다".into()),
+    ]);
+
+    backend.inner_mut().prints.clear();
+    backend.clear();
+    backend.print_at(Vec2::new(0, 0), "ABXDEF");
+    backend.print_at(Vec2::new(2, 1), "가라다");
+    backend.flush();
+
+    assert_eq!(backend.inner().prints, &[
+        (Vec2::new(2, 0), "X".into()),
+        (Vec2::new(4, 1), "라".into()),
+    ]);
+
+    backend.inner_mut().prints.clear();
+    backend.clear();
+    backend.print_at(Vec2::new(0, 0), "ABXDEF");
+    backend.print_at(Vec2::new(2, 1), "가라다");
+    backend.flush();
+
+    assert!(backend.inner().prints.is_empty());
+    pretty_assertions::assert_eq!(backend.inner().inner.lines(), &[
+        "ABXDEF    ",
+        "  가라다  ",
+        "          ",
+    ]);
+}
+
+#[cfg(feature = "test-backend")]
+#[test]
+fn buffered_backend_keep_graphemes() {
+    use crate::backend::TestBackend;
+
+    let mut backend = BufferedBackend::new(Recorder {
+        inner:  TestBackend::new(Vec2::new(10, 1)),
+        prints: Vec::new(),
+    });
+
+    backend.clear();
+    backend.print_at(Vec2::new(0, 0), "e\u{301}x");
+    backend.print_at(Vec2::new(2, 0), "가");
+    backend.print_at(Vec2::new(4, 0), "\u{308}");
+    backend.print_at(Vec2::new(5, 0), "👩\u{200d}💻!");
+    backend.flush();
+
+    assert_eq!(backend.inner().prints, &[
+        (Vec2::new(0, 0), "e\u{301}x가\u{308}".into()),
+        (Vec2::new(5, 0), "👩\u{200d}💻!".into()),
+    ]);
+
+    backend.inner_mut().prints.clear();
+    backend.clear();
+    backend.print_at(Vec2::new(0, 0), "e\u{301}x");
+    backend.print_at(Vec2::new(2, 0), "가");
+    backend.print_at(Vec2::new(5, 0), "👩\u{200d}💻!");
+    backend.flush();
+
+    assert_eq!(backend.inner().prints, &[(Vec2::new(2, 0), "가".into())]);
+}
@@ -135,75 +135,15 @@ impl<W: Write> CrosstermBackendGuard<W> {
     }
 }
 
-macro_rules! code_is {
-    ($event:expr, $code:ident) => {
-        if let KeyEvent {
-            code: KeyCode::$code,
-            modifiers,
-        } = $event
-        {
-            modifiers.is_empty()
-        } else {
-            false
-        }
-    };
-}
-
 impl KeyEventLike for KeyEvent {
-    fn try_tab(&self) -> bool {
-        code_is!(self, Tab)
-    }
-
-    fn try_up(&self) -> bool {
-        code_is!(self, Up)
-    }
-
-    fn try_down(&self) -> bool {
-        code_is!(self, Down)
-    }
-
-    fn try_left(&self) -> bool {
-        code_is!(self, Left)
-    }
-
-    fn try_right(&self) -> bool {
-        code_is!(self, Right)
-    }
-
-    fn try_char(&self) -> Option<char> {
-        if let KeyEvent {
-            code: KeyCode::Char(ch),
-            modifiers,
-        } = self
-        {
-            if modifiers.is_empty() {
-                Some(*ch)
-            } else {
-                None
-            }
-        } else {
-            None
-        }
-    }
-
-    fn try_ctrl_char(&self) -> Option<char> {
-        if let KeyEvent {
-            code: KeyCode::Char(ch),
-            modifiers: KeyModifiers::CONTROL,
-        } = self
-        {
-            Some(*ch)
-        } else {
-            None
-        }
-    }
-
-    fn try_enter(&self) -> bool {
-        code_is!(self, Enter)
+    #[inline]
+    fn code(&self) -> crate::event::KeyCode {
+        self.code.into()
     }
 
-    fn try_backspace(&self) -> bool {
-        code_is!(self, Backspace)
+    #[inline]
+    fn modifiers(&self) -> crate::event::KeyModifiers {
+        convert_modifiers(self.modifiers)
     }
 }
 
@@ -218,6 +158,16 @@ impl MouseEventLike for MouseEvent {
         }
     }
 
+    fn modifiers(&self) -> crate::event::KeyModifiers {
+        match self {
+            MouseEvent::Down(.., modifiers)
+            | MouseEvent::Up(.., modifiers)
+            | MouseEvent::Drag(.., modifiers)
+            | MouseEvent::ScrollUp(.., modifiers)
+            | MouseEvent::ScrollDown(.., modifiers) => convert_modifiers(*modifiers),
+        }
+    }
+
     fn map_pos(
         &mut self,
         f: impl FnOnce(Vec2) -> Vec2,
@@ -272,6 +222,34 @@ impl MouseEventLike for MouseEvent {
         }
     }
 
+    fn try_right_down(&self) -> Option<Vec2> {
+        match self {
+            MouseEvent::Down(MouseButton::Right, x, y, ..) => Some((*x, *y).into()),
+            _ => None,
+        }
+    }
+
+    fn try_right_up(&self) -> Option<Vec2> {
+        match self {
+            MouseEvent::Up(MouseButton::Right, x, y, ..) => Some((*x, *y).into()),
+            _ => None,
+        }
+    }
+
+    fn try_middle_down(&self) -> Option<Vec2> {
+        match self {
+            MouseEvent::Down(MouseButton::Middle, x, y, ..) => Some((*x, *y).into()),
+            _ => None,
+        }
+    }
+
+    fn try_middle_up(&self) -> Option<Vec2> {
+        match self {
+            MouseEvent::Up(MouseButton::Middle, x, y, ..) => Some((*x, *y).into()),
+            _ => None,
+        }
+    }
+
     fn from_left_down(pos: Vec2) -> Self {
         MouseEvent::Down(MouseButton::Left, pos.x, pos.y, KeyModifiers::empty())
     }
@@ -341,3 +319,141 @@ impl EventLike for Event {
         }
     }
 }
+
+fn convert_modifiers(modifiers: KeyModifiers) -> crate::event::KeyModifiers {
+    use crate::event::KeyModifier;
+
+    let mut ret = crate::event::KeyModifiers::empty();
+
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        ret |= KeyModifier::Shift;
+    }
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        ret |= KeyModifier::Control;
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        ret |= KeyModifier::Alt;
+    }
+
+    ret
+}
+
+impl From<KeyCode> for crate::event::KeyCode {
+    fn from(code: KeyCode) -> Self {
+        match code {
+            KeyCode::Backspace => Self::Backspace,
+            KeyCode::Enter => Self::Enter,
+            KeyCode::Left => Self::Left,
+            KeyCode::Right => Self::Right,
+            KeyCode::Up => Self::Up,
+            KeyCode::Down => Self::Down,
+            KeyCode::Home => Self::Home,
+            KeyCode::End => Self::End,
+            KeyCode::PageUp => Self::PageUp,
+            KeyCode::PageDown => Self::PageDown,
+            KeyCode::Tab => Self::Tab,
+            KeyCode::BackTab => Self::BackTab,
+            KeyCode::Delete => Self::Delete,
+            KeyCode::Insert => Self::Insert,
+            KeyCode::F(n) => Self::F(n),
+            KeyCode::Char(ch) => Self::Char(ch),
+            KeyCode::Null => Self::Null,
+            KeyCode::Esc => Self::Esc,
+        }
+    }
+}
+
+impl From<KeyEvent> for crate::event::KeyEvent {
+    #[inline]
+    fn from(ke: KeyEvent) -> Self {
+        Self::new(ke.code.into(), convert_modifiers(ke.modifiers))
+    }
+}
+
+impl From<MouseButton> for crate::event::MouseButton {
+    #[inline]
+    fn from(btn: MouseButton) -> Self {
+        match btn {
+            MouseButton::Left => Self::Left,
+            MouseButton::Right => Self::Right,
+            MouseButton::Middle => Self::Middle,
+        }
+    }
+}
+
+impl From<MouseEvent> for crate::event::MouseEvent {
+    fn from(me: MouseEvent) -> Self {
+        use crate::event::MouseEventKind;
+
+        let (kind, x, y, modifiers) = match me {
+            MouseEvent::Down(btn, x, y, modifiers) => {
+                (MouseEventKind::Down(btn.into()), x, y, modifiers)
+            }
+            MouseEvent::Up(btn, x, y, modifiers) => {
+                (MouseEventKind::Up(btn.into()), x, y, modifiers)
+            }
+            MouseEvent::Drag(btn, x, y, modifiers) => {
+                (MouseEventKind::Drag(btn.into()), x, y, modifiers)
+            }
+            MouseEvent::ScrollUp(x, y, modifiers) => (MouseEventKind::ScrollUp, x, y, modifiers),
+            MouseEvent::ScrollDown(x, y, modifiers) => {
+                (MouseEventKind::ScrollDown, x, y, modifiers)
+            }
+        };
+
+        Self {
+            kind,
+            pos: Vec2::new(x, y),
+            modifiers: convert_modifiers(modifiers),
+        }
+    }
+}
+
+impl From<Event> for crate::event::Event {
+    #[inline]
+    fn from(e: Event) -> Self {
+        match e {
+            Event::Key(ke) => Self::Key(ke.into()),
+            Event::Mouse(me) => Self::Mouse(me.into()),
+            Event::Resize(x, y) => Self::Resize(Vec2::new(x, y)),
+        }
+    }
+}
+
+#[test]
+fn convert_crossterm_event() {
+    use crate::event::{
+        KeyModifier,
+        MouseEventKind,
+    };
+
+    assert_eq!(
+        crate::event::Event::from(Event::Key(KeyEvent {
+            code:      KeyCode::BackTab,
+            modifiers: KeyModifiers::SHIFT,
+        })),
+        crate::event::Event::Key(crate::event::KeyEvent::new(
+            crate::event::KeyCode::BackTab,
+            KeyModifier::Shift,
+        )),
+    );
+
+    assert_eq!(
+        crate::event::Event::from(Event::Mouse(MouseEvent::Drag(
+            MouseButton::Right,
+            3,
+            4,
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        ))),
+        crate::event::Event::Mouse(crate::event::MouseEvent {
+            kind:      MouseEventKind::Drag(crate::event::MouseButton::Right),
+            pos:       Vec2::new(3, 4),
+            modifiers: KeyModifier::Control | KeyModifier::Alt,
+        }),
+    );
+
+    assert_eq!(
+        crate::event::Event::from(Event::Resize(80, 24)),
+        crate::event::Event::Resize(Vec2::new(80, 24)),
+    );
+}
@@ -0,0 +1,54 @@
+use crate::{
+    backend::Backend,
+    style::AnsiStyle as Style,
+    vec2::Vec2,
+};
+
+/// Adds dim effect to every style set on inner backend
+pub struct DimmedBackend<'a>(&'a mut dyn Backend);
+
+impl<'a> DimmedBackend<'a> {
+    pub fn new(backend: &'a mut dyn Backend) -> Self {
+        Self(backend)
+    }
+}
+
+impl<'a> Backend for DimmedBackend<'a> {
+    #[inline]
+    fn print_at(
+        &mut self,
+        pos: Vec2,
+        text: &str,
+    ) {
+        self.0.print_at(pos, text);
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    #[inline]
+    fn size(&self) -> Vec2 {
+        self.0.size()
+    }
+
+    #[inline]
+    fn flush(&mut self) {
+        self.0.flush();
+    }
+
+    #[inline]
+    fn set_style(
+        &mut self,
+        mut style: Style,
+    ) {
+        style.is_dimmed = true;
+        self.0.set_style(style);
+    }
+
+    #[inline]
+    fn style(&self) -> Style {
+        self.0.style()
+    }
+}
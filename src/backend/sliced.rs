@@ -4,14 +4,34 @@ use crate::{
     vec2::Vec2,
 };
 
-pub struct SlicedBackend<'a>(&'a mut dyn Backend, Vec2);
+/// Moves everything printed by `pos` towards the origin
+///
+/// Text which would land left of or above `start` is cut off.
+pub struct SlicedBackend<'a> {
+    backend: &'a mut dyn Backend,
+    pos:     Vec2,
+    start:   Vec2,
+}
 
 impl<'a> SlicedBackend<'a> {
+    #[inline]
     pub fn new(
         backend: &'a mut dyn Backend,
         pos: Vec2,
     ) -> Self {
-        Self(backend, pos)
+        Self::with_start(backend, pos, Vec2::new(0, 0))
+    }
+
+    pub fn with_start(
+        backend: &'a mut dyn Backend,
+        pos: Vec2,
+        start: Vec2,
+    ) -> Self {
+        Self {
+            backend,
+            pos,
+            start,
+        }
     }
 }
 
@@ -22,36 +42,41 @@ impl<'a> Backend for SlicedBackend<'a> {
         pos: Vec2,
         text: &str,
     ) {
-        if pos.y < self.1.y {
+        let min = self.start + self.pos;
+
+        if pos.y < min.y {
             return;
         }
 
-        if pos.x >= self.1.x {
-            self.0.print_at(pos - self.1, text);
+        let y = pos.y - self.pos.y;
+
+        if pos.x >= min.x {
+            self.backend
+                .print_at(Vec2::new(pos.x - self.pos.x, y), text);
+            return;
         }
 
-        let (_, sub_str, left) =
-            crate::util::slice_str_with_width(text, (self.1.x - pos.x) as usize);
+        let (_, sub_str, left) = crate::util::slice_str_with_width(text, (min.x - pos.x) as usize);
 
         if !sub_str.is_empty() {
-            self.0
-                .print_at((left as u16, pos.y - self.1.y).into(), sub_str);
+            self.backend
+                .print_at(Vec2::new(self.start.x + left as u16, y), sub_str);
         }
     }
 
     #[inline]
     fn clear(&mut self) {
-        self.0.clear();
+        self.backend.clear();
     }
 
     #[inline]
     fn size(&self) -> Vec2 {
-        self.0.size() + self.1
+        self.backend.size() + self.pos
     }
 
     #[inline]
     fn flush(&mut self) {
-        self.0.flush();
+        self.backend.flush();
     }
 
     #[inline]
@@ -59,11 +84,11 @@ impl<'a> Backend for SlicedBackend<'a> {
         &mut self,
         style: Style,
     ) {
-        self.0.set_style(style);
+        self.backend.set_style(style);
     }
 
     #[inline]
     fn style(&self) -> Style {
-        self.0.style()
+        self.backend.style()
     }
 }
@@ -1,16 +1,26 @@
 use crate::{
     backend::Backend,
-    style::AnsiStyle as Style,
+    style::{
+        AnsiColor as Color,
+        AnsiStyle as Style,
+    },
     vec2::Vec2,
 };
 
-use std::iter;
-use unicode_width::UnicodeWidthStr;
+use std::{
+    fmt::Write,
+    iter,
+};
+use unicode_width::{
+    UnicodeWidthChar,
+    UnicodeWidthStr,
+};
 
 pub struct TestBackend {
-    lines: Vec<String>,
-    style: Style,
-    size:  Vec2,
+    lines:  Vec<String>,
+    styles: Vec<Vec<Style>>,
+    style:  Style,
+    size:   Vec2,
 }
 
 impl TestBackend {
@@ -19,6 +29,9 @@ impl TestBackend {
             lines: iter::repeat_with(|| " ".repeat(size.x as usize))
                 .take(size.y as usize)
                 .collect(),
+            styles: iter::repeat_with(|| vec![Style::default(); size.x as usize])
+                .take(size.y as usize)
+                .collect(),
             style: Style::default(),
             size,
         }
@@ -27,6 +40,107 @@ impl TestBackend {
     pub fn lines(&self) -> &[String] {
         &self.lines[..]
     }
+
+    /// Style of each cell, indexed by `[y][x]`
+    pub fn styles(&self) -> &[Vec<Style>] {
+        &self.styles[..]
+    }
+
+    /// Lines with inline style markup
+    ///
+    /// Each run of cells which isn't default style is wrapped with `[attrs]text[/]`
+    /// e.g. `[b,yellow,bg=blue]text[/]`.
+    /// Effects come first (`b`, `dim`, `i`, `u`, `blink`, `reverse`, `hidden`, `strike`)
+    /// then foreground color by name and background color as `bg=name`.
+    pub fn styled_lines(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .zip(self.styles.iter())
+            .map(|(line, styles)| {
+                let mut ret = String::with_capacity(line.len());
+                let mut current = Style::default();
+                let mut x = 0;
+
+                for ch in line.chars() {
+                    let style = styles.get(x).copied().unwrap_or_default();
+
+                    if style != current {
+                        if current != Style::default() {
+                            ret.push_str("[/]");
+                        }
+                        if style != Style::default() {
+                            ret.push('[');
+                            ret.push_str(&style_markup(style));
+                            ret.push(']');
+                        }
+                        current = style;
+                    }
+
+                    ret.push(ch);
+                    x += ch.width().unwrap_or(0);
+                }
+
+                if current != Style::default() {
+                    ret.push_str("[/]");
+                }
+
+                ret
+            })
+            .collect()
+    }
+}
+
+fn color_markup(
+    out: &mut String,
+    color: Color,
+) {
+    match color {
+        Color::Black => out.push_str("black"),
+        Color::Red => out.push_str("red"),
+        Color::Green => out.push_str("green"),
+        Color::Yellow => out.push_str("yellow"),
+        Color::Blue => out.push_str("blue"),
+        Color::Purple => out.push_str("purple"),
+        Color::Cyan => out.push_str("cyan"),
+        Color::White => out.push_str("white"),
+        Color::Fixed(n) => write!(out, "fixed({})", n).unwrap(),
+        Color::RGB(r, g, b) => write!(out, "#{:02x}{:02x}{:02x}", r, g, b).unwrap(),
+    }
+}
+
+fn style_markup(style: Style) -> String {
+    let mut attrs = Vec::with_capacity(4);
+
+    let effects = [
+        (style.is_bold, "b"),
+        (style.is_dimmed, "dim"),
+        (style.is_italic, "i"),
+        (style.is_underline, "u"),
+        (style.is_blink, "blink"),
+        (style.is_reverse, "reverse"),
+        (style.is_hidden, "hidden"),
+        (style.is_strikethrough, "strike"),
+    ];
+
+    for (enabled, name) in effects.iter() {
+        if *enabled {
+            attrs.push(name.to_string());
+        }
+    }
+
+    if let Some(fg) = style.foreground {
+        let mut attr = String::new();
+        color_markup(&mut attr, fg);
+        attrs.push(attr);
+    }
+
+    if let Some(bg) = style.background {
+        let mut attr = String::from("bg=");
+        color_markup(&mut attr, bg);
+        attrs.push(attr);
+    }
+
+    attrs.join(",")
 }
 
 impl Backend for TestBackend {
@@ -38,6 +152,12 @@ impl Backend for TestBackend {
                 line.push(' ');
             }
         }
+
+        for styles in &mut self.styles {
+            for style in styles.iter_mut() {
+                *style = Style::default();
+            }
+        }
     }
 
     #[inline]
@@ -82,6 +202,12 @@ impl Backend for TestBackend {
         }
 
         line.replace_range(start..end, text);
+
+        let styles = &mut self.styles[pos.y as usize];
+        let style_end = (pos.x as usize + width).min(styles.len());
+        for style in styles[(pos.x as usize).min(style_end)..style_end].iter_mut() {
+            *style = self.style;
+        }
     }
 
     #[inline]
@@ -103,3 +229,18 @@ fn test_backend_test() {
         "          ",
     ]);
 }
+
+#[test]
+fn test_backend_styled_test() {
+    let mut backend = TestBackend::new(Vec2::new(10, 2));
+    backend.print_at(Vec2::new(0, 0), "AB");
+    backend.set_style(Style::new().bold().fg(Color::Yellow));
+    backend.print_at(Vec2::new(2, 0), "가나");
+    backend.set_style(Style::new().reverse().on(Color::Blue));
+    backend.print_at(Vec2::new(1, 1), "CD");
+
+    pretty_assertions::assert_eq!(backend.styled_lines(), &[
+        "AB[b,yellow]가나[/]    ",
+        " [reverse,bg=blue]CD[/]       ",
+    ]);
+}
@@ -1,12 +1,26 @@
 use crate::vec2::Vec2;
+use enumset::{
+    EnumSet,
+    EnumSetType,
+};
 
 pub trait MouseEventLike: Sized {
     fn try_left_down(&self) -> Option<Vec2>;
     fn try_left_up(&self) -> Option<Vec2>;
+    fn try_right_down(&self) -> Option<Vec2>;
+    fn try_right_up(&self) -> Option<Vec2>;
+    fn try_middle_down(&self) -> Option<Vec2>;
+    fn try_middle_up(&self) -> Option<Vec2>;
     fn try_drag(&self) -> Option<Vec2>;
     fn try_scroll_up(&self) -> Option<Vec2>;
     fn try_scroll_down(&self) -> Option<Vec2>;
     fn pos(&self) -> Vec2;
+    /// Keys held while mouse event happened, none by default for backends which don't report
+    /// them
+    #[inline]
+    fn modifiers(&self) -> KeyModifiers {
+        KeyModifiers::empty()
+    }
     fn map_pos(
         &mut self,
         f: impl FnOnce(Vec2) -> Vec2,
@@ -18,18 +32,150 @@ pub trait MouseEventLike: Sized {
 
     fn from_left_down(pos: Vec2) -> Self;
     fn from_left_up(pos: Vec2) -> Self;
+
+    /// Press of any button
+    #[inline]
+    fn try_any_down(&self) -> Option<Vec2> {
+        self.try_left_down()
+            .or_else(|| self.try_right_down())
+            .or_else(|| self.try_middle_down())
+    }
 }
 
 pub trait KeyEventLike {
-    fn try_char(&self) -> Option<char>;
-    fn try_ctrl_char(&self) -> Option<char>;
-    fn try_enter(&self) -> bool;
-    fn try_up(&self) -> bool;
-    fn try_down(&self) -> bool;
-    fn try_left(&self) -> bool;
-    fn try_right(&self) -> bool;
-    fn try_backspace(&self) -> bool;
-    fn try_tab(&self) -> bool;
+    fn code(&self) -> KeyCode;
+    fn modifiers(&self) -> KeyModifiers;
+
+    #[inline]
+    fn key(&self) -> KeyEvent {
+        KeyEvent::new(self.code(), self.modifiers())
+    }
+
+    /// Exact match of key code and modifiers
+    #[inline]
+    fn is_key(
+        &self,
+        code: KeyCode,
+        modifiers: impl Into<KeyModifiers>,
+    ) -> bool {
+        self.code() == code && self.modifiers() == modifiers.into()
+    }
+
+    #[inline]
+    fn is_plain(
+        &self,
+        code: KeyCode,
+    ) -> bool {
+        self.is_key(code, KeyModifiers::empty())
+    }
+
+    #[inline]
+    fn try_char(&self) -> Option<char> {
+        match self.code() {
+            // Uppercase letters may be reported with shift
+            KeyCode::Char(ch) if (self.modifiers() - KeyModifier::Shift).is_empty() => Some(ch),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_ctrl_char(&self) -> Option<char> {
+        match self.code() {
+            KeyCode::Char(ch) if self.modifiers() == KeyModifier::Control => Some(ch),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_alt_char(&self) -> Option<char> {
+        match self.code() {
+            KeyCode::Char(ch) if self.modifiers() == KeyModifier::Alt => Some(ch),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_function(&self) -> Option<u8> {
+        match self.code() {
+            KeyCode::F(n) if self.modifiers().is_empty() => Some(n),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_enter(&self) -> bool {
+        self.is_plain(KeyCode::Enter)
+    }
+
+    #[inline]
+    fn try_up(&self) -> bool {
+        self.is_plain(KeyCode::Up)
+    }
+
+    #[inline]
+    fn try_down(&self) -> bool {
+        self.is_plain(KeyCode::Down)
+    }
+
+    #[inline]
+    fn try_left(&self) -> bool {
+        self.is_plain(KeyCode::Left)
+    }
+
+    #[inline]
+    fn try_right(&self) -> bool {
+        self.is_plain(KeyCode::Right)
+    }
+
+    #[inline]
+    fn try_home(&self) -> bool {
+        self.is_plain(KeyCode::Home)
+    }
+
+    #[inline]
+    fn try_end(&self) -> bool {
+        self.is_plain(KeyCode::End)
+    }
+
+    #[inline]
+    fn try_page_up(&self) -> bool {
+        self.is_plain(KeyCode::PageUp)
+    }
+
+    #[inline]
+    fn try_page_down(&self) -> bool {
+        self.is_plain(KeyCode::PageDown)
+    }
+
+    #[inline]
+    fn try_backspace(&self) -> bool {
+        self.is_plain(KeyCode::Backspace)
+    }
+
+    #[inline]
+    fn try_delete(&self) -> bool {
+        self.is_plain(KeyCode::Delete)
+    }
+
+    #[inline]
+    fn try_esc(&self) -> bool {
+        self.is_plain(KeyCode::Esc)
+    }
+
+    #[inline]
+    fn try_tab(&self) -> bool {
+        self.is_plain(KeyCode::Tab)
+    }
+
+    /// Shift+Tab, some terminals report it as `BackTab` with or without shift
+    #[inline]
+    fn try_back_tab(&self) -> bool {
+        match self.code() {
+            KeyCode::BackTab => (self.modifiers() - KeyModifier::Shift).is_empty(),
+            KeyCode::Tab => self.modifiers() == KeyModifier::Shift,
+            _ => false,
+        }
+    }
 }
 
 pub trait EventLike: Sized {
@@ -42,3 +188,287 @@ pub trait EventLike: Sized {
     fn try_key_mut(&mut self) -> Option<&mut Self::KeyEvent>;
     fn try_resize(&self) -> Option<Vec2>;
 }
+
+/// Backend independent terminal event
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize(Vec2),
+    Paste(String),
+    FocusGained,
+    FocusLost,
+}
+
+#[derive(EnumSetType, Debug, Hash)]
+pub enum KeyModifier {
+    Shift,
+    Control,
+    Alt,
+}
+
+pub type KeyModifiers = EnumSet<KeyModifier>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum KeyCode {
+    Backspace,
+    Enter,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Tab,
+    BackTab,
+    Delete,
+    Insert,
+    F(u8),
+    Char(char),
+    Null,
+    Esc,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct KeyEvent {
+    pub code:      KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyEvent {
+    #[inline]
+    pub fn new(
+        code: KeyCode,
+        modifiers: impl Into<KeyModifiers>,
+    ) -> Self {
+        Self {
+            code,
+            modifiers: modifiers.into(),
+        }
+    }
+}
+
+impl From<KeyCode> for KeyEvent {
+    #[inline]
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::empty())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MouseEventKind {
+    Down(MouseButton),
+    Up(MouseButton),
+    Drag(MouseButton),
+    ScrollUp,
+    ScrollDown,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct MouseEvent {
+    pub kind:      MouseEventKind,
+    pub pos:       Vec2,
+    pub modifiers: KeyModifiers,
+}
+
+impl MouseEvent {
+    #[inline]
+    pub fn new(
+        kind: MouseEventKind,
+        pos: impl Into<Vec2>,
+    ) -> Self {
+        Self {
+            kind,
+            pos: pos.into(),
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
+    #[inline]
+    fn try_kind(
+        &self,
+        kind: MouseEventKind,
+    ) -> Option<Vec2> {
+        if self.kind == kind {
+            Some(self.pos)
+        } else {
+            None
+        }
+    }
+}
+
+impl KeyEventLike for KeyEvent {
+    #[inline]
+    fn code(&self) -> KeyCode {
+        self.code
+    }
+
+    #[inline]
+    fn modifiers(&self) -> KeyModifiers {
+        self.modifiers
+    }
+}
+
+impl MouseEventLike for MouseEvent {
+    #[inline]
+    fn try_left_down(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::Down(MouseButton::Left))
+    }
+
+    #[inline]
+    fn try_left_up(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::Up(MouseButton::Left))
+    }
+
+    #[inline]
+    fn try_right_down(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::Down(MouseButton::Right))
+    }
+
+    #[inline]
+    fn try_right_up(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::Up(MouseButton::Right))
+    }
+
+    #[inline]
+    fn try_middle_down(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::Down(MouseButton::Middle))
+    }
+
+    #[inline]
+    fn try_middle_up(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::Up(MouseButton::Middle))
+    }
+
+    #[inline]
+    fn try_drag(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::Drag(MouseButton::Left))
+    }
+
+    #[inline]
+    fn try_scroll_up(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::ScrollUp)
+    }
+
+    #[inline]
+    fn try_scroll_down(&self) -> Option<Vec2> {
+        self.try_kind(MouseEventKind::ScrollDown)
+    }
+
+    #[inline]
+    fn pos(&self) -> Vec2 {
+        self.pos
+    }
+
+    #[inline]
+    fn modifiers(&self) -> KeyModifiers {
+        self.modifiers
+    }
+
+    #[inline]
+    fn map_pos(
+        &mut self,
+        f: impl FnOnce(Vec2) -> Vec2,
+    ) {
+        self.pos = f(self.pos);
+    }
+
+    #[inline]
+    fn filter_map_pos(
+        &mut self,
+        f: impl FnOnce(Vec2) -> Option<Vec2>,
+    ) -> bool {
+        match f(self.pos) {
+            Some(pos) => {
+                self.pos = pos;
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn from_left_down(pos: Vec2) -> Self {
+        Self::new(MouseEventKind::Down(MouseButton::Left), pos)
+    }
+
+    #[inline]
+    fn from_left_up(pos: Vec2) -> Self {
+        Self::new(MouseEventKind::Up(MouseButton::Left), pos)
+    }
+}
+
+impl EventLike for Event {
+    type KeyEvent = KeyEvent;
+    type MouseEvent = MouseEvent;
+
+    #[inline]
+    fn try_mouse(&self) -> Option<&Self::MouseEvent> {
+        match self {
+            Event::Mouse(me) => Some(me),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_mouse_mut(&mut self) -> Option<&mut Self::MouseEvent> {
+        match self {
+            Event::Mouse(me) => Some(me),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_key(&self) -> Option<&Self::KeyEvent> {
+        match self {
+            Event::Key(ke) => Some(ke),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_key_mut(&mut self) -> Option<&mut Self::KeyEvent> {
+        match self {
+            Event::Key(ke) => Some(ke),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_resize(&self) -> Option<Vec2> {
+        match self {
+            Event::Resize(size) => Some(*size),
+            _ => None,
+        }
+    }
+}
+
+impl From<KeyEvent> for Event {
+    #[inline]
+    fn from(ke: KeyEvent) -> Self {
+        Event::Key(ke)
+    }
+}
+
+impl From<KeyCode> for Event {
+    #[inline]
+    fn from(code: KeyCode) -> Self {
+        Event::Key(code.into())
+    }
+}
+
+impl From<MouseEvent> for Event {
+    #[inline]
+    fn from(me: MouseEvent) -> Self {
+        Event::Mouse(me)
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{
     backend::Backend,
+    focus::FocusDirection,
     printer::Printer,
     state::RedrawState,
     style::Theme,
-    vec2::Vec2,
     view::View,
 };
 
+#[cfg(feature = "test-backend")]
+use crate::vec2::Vec2;
+
 pub fn simple<S: RedrawState, E, B: Backend, V: View<S, E, Message = bool>>(
     state: &mut S,
     backend: &mut B,
@@ -16,6 +19,7 @@ pub fn simple<S: RedrawState, E, B: Backend, V: View<S, E, Message = bool>>(
 ) {
     backend.clear();
     state.set_need_redraw(true);
+    view.take_focus(FocusDirection::Forward);
 
     loop {
         if state.is_need_redraw() {
@@ -44,14 +48,16 @@ pub fn bench<B: Backend, E, V: View<bool, E>>(
     events: impl IntoIterator<Item = E>,
 ) {
     let theme = Theme::default();
-    let mut printer = Printer::new(backend, &theme);
 
     let mut need_redraw = true;
+    view.take_focus(FocusDirection::Forward);
 
     for event in events {
         if need_redraw {
-            view.layout(printer.bound().size());
-            view.render(&mut printer);
+            backend.clear();
+            view.layout(backend.size());
+            view.render(&mut Printer::new(backend, &theme));
+            backend.flush();
             need_redraw = false;
         }
 
@@ -65,6 +71,29 @@ pub fn test<E, V: View<bool, E>>(
     events: impl IntoIterator<Item = E>,
     size: Vec2,
     cb: impl FnOnce(&[String]),
+) {
+    test_backend(view, events, size, |backend| cb(backend.lines()));
+}
+
+/// Same as [`test`] but lines contain style markup from [`TestBackend::styled_lines`]
+///
+/// [`TestBackend::styled_lines`]: crate::backend::TestBackend::styled_lines
+#[cfg(feature = "test-backend")]
+pub fn test_styled<E, V: View<bool, E>>(
+    view: &mut V,
+    events: impl IntoIterator<Item = E>,
+    size: Vec2,
+    cb: impl FnOnce(&[String]),
+) {
+    test_backend(view, events, size, |backend| cb(&backend.styled_lines()));
+}
+
+#[cfg(feature = "test-backend")]
+pub fn test_backend<E, V: View<bool, E>>(
+    view: &mut V,
+    events: impl IntoIterator<Item = E>,
+    size: Vec2,
+    cb: impl FnOnce(&crate::backend::TestBackend),
 ) {
     let theme = Theme::default();
     let mut backend = crate::backend::TestBackend::new(size);
@@ -72,6 +101,7 @@ pub fn test<E, V: View<bool, E>>(
 
     let mut state = false;
 
+    view.take_focus(FocusDirection::Forward);
     view.layout(size);
     view.render(&mut printer);
 
@@ -85,5 +115,5 @@ pub fn test<E, V: View<bool, E>>(
         view.render(&mut printer);
     }
 
-    cb(backend.lines());
+    cb(&backend);
 }
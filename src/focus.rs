@@ -0,0 +1,96 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FocusDirection {
+    Forward,
+    Backward,
+}
+
+/// Walk focus over `len` children for [`View::take_focus`] of containers
+///
+/// `current` is the child which has focus now. `take` is called with child index until one
+/// of them accepts focus, the index of it is returned.
+/// `None` means focus moved out of the children.
+///
+/// [`View::take_focus`]: crate::view::View::take_focus
+pub fn walk_focus(
+    len: usize,
+    current: Option<usize>,
+    direction: FocusDirection,
+    mut take: impl FnMut(usize, FocusDirection) -> bool,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let mut i = match (current, direction) {
+        (Some(i), _) => i,
+        (None, FocusDirection::Forward) => 0,
+        (None, FocusDirection::Backward) => len - 1,
+    };
+
+    loop {
+        if take(i, direction) {
+            return Some(i);
+        }
+
+        i = match direction {
+            FocusDirection::Forward if i + 1 < len => i + 1,
+            FocusDirection::Backward if i > 0 => i - 1,
+            _ => return None,
+        };
+    }
+}
+
+/// Same as [`walk_focus`] but starts again from the other end when focus moves out of the
+/// children, used for `Tab` and `BackTab`
+///
+/// `None` means no child accepts focus.
+pub fn cycle_focus(
+    len: usize,
+    current: Option<usize>,
+    direction: FocusDirection,
+    mut take: impl FnMut(usize, FocusDirection) -> bool,
+) -> Option<usize> {
+    walk_focus(len, current, direction, &mut take)
+        .or_else(|| walk_focus(len, None, direction, take))
+}
+
+#[test]
+fn walk_focus_test() {
+    let focusable = [false, true, false, true];
+    let mut focused = [false; 4];
+
+    let mut step = |current: Option<usize>, direction| {
+        walk_focus(4, current, direction, |i, _| {
+            focused[i] = focusable[i] && !focused[i];
+            focused[i]
+        })
+    };
+
+    assert_eq!(step(None, FocusDirection::Forward), Some(1));
+    assert_eq!(step(Some(1), FocusDirection::Forward), Some(3));
+    assert_eq!(step(Some(3), FocusDirection::Forward), None);
+    assert_eq!(step(None, FocusDirection::Backward), Some(3));
+    assert_eq!(step(Some(3), FocusDirection::Backward), Some(1));
+}
+
+#[test]
+fn cycle_focus_test() {
+    let focusable = [false, true, false, true];
+    let mut focused = [false; 4];
+
+    let mut step = |current: Option<usize>, direction| {
+        cycle_focus(4, current, direction, |i, _| {
+            focused[i] = focusable[i] && !focused[i];
+            focused[i]
+        })
+    };
+
+    assert_eq!(step(None, FocusDirection::Forward), Some(1));
+    assert_eq!(step(Some(1), FocusDirection::Forward), Some(3));
+    assert_eq!(step(Some(3), FocusDirection::Forward), Some(1));
+    assert_eq!(step(Some(1), FocusDirection::Backward), Some(3));
+    assert_eq!(
+        cycle_focus(2, None, FocusDirection::Forward, |_, _| false),
+        None
+    );
+}
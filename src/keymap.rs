@@ -0,0 +1,573 @@
+use crate::event::{
+    KeyCode,
+    KeyEvent,
+    KeyModifier,
+    KeyModifiers,
+};
+use std::{
+    error::Error,
+    fmt,
+    str::FromStr,
+};
+
+/// Maps key sequences to messages
+///
+/// Key sequences are written as whitespace separated keys like `ctrl-x ctrl-s` or `g g`,
+/// see [`parse_keys`] for key syntax.
+#[derive(Clone, Debug)]
+pub struct KeyMap<M> {
+    bindings: Vec<(Vec<KeyEvent>, M)>,
+}
+
+/// Result of looking up pressed keys in [`KeyMap`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyLookup<'a, M> {
+    /// Keys match a binding
+    Match(&'a M),
+    /// Keys are start of at least one binding, wait for more keys
+    Prefix,
+    None,
+}
+
+impl<M> Default for KeyMap<M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> KeyMap<M> {
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::with_capacity(10),
+        }
+    }
+
+    /// Parse config which has one `keys = action` binding per line
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    /// `f` converts action name into message.
+    pub fn from_config(
+        config: &str,
+        f: impl FnMut(&str) -> Option<M>,
+    ) -> Result<Self, KeyMapError> {
+        let mut ret = Self::new();
+        ret.load_config(config, f)?;
+        Ok(ret)
+    }
+
+    /// Add bindings from config, bindings for same keys are replaced
+    ///
+    /// Nothing is added when any line is invalid.
+    pub fn load_config(
+        &mut self,
+        config: &str,
+        mut f: impl FnMut(&str) -> Option<M>,
+    ) -> Result<(), KeyMapError> {
+        let mut bindings: Vec<(Vec<KeyEvent>, M)> = Vec::new();
+
+        for (line_no, line) in config.lines().enumerate() {
+            let line_no = line_no + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (keys, action) = match line.rfind('=') {
+                Some(pos) => (line[..pos].trim(), line[pos + 1..].trim()),
+                None => return Err(KeyMapError::new(line_no, KeyMapErrorKind::MissingAction)),
+            };
+
+            let keys =
+                normalize_keys(parse_keys(keys).map_err(|kind| KeyMapError::new(line_no, kind))?);
+
+            let existing = self.bindings.iter().chain(bindings.iter());
+            if let Some(kind) = check_conflict(existing, &keys) {
+                return Err(KeyMapError::new(line_no, kind));
+            }
+
+            let msg = f(action).ok_or_else(|| {
+                KeyMapError::new(line_no, KeyMapErrorKind::UnknownAction(action.into()))
+            })?;
+
+            bindings.push((keys, msg));
+        }
+
+        for (keys, msg) in bindings {
+            self.insert(keys, msg);
+        }
+
+        Ok(())
+    }
+
+    /// Builder version of [`KeyMap::add_binding`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keys` isn't valid key sequence or conflicts with other binding
+    #[inline]
+    pub fn bind(
+        mut self,
+        keys: &str,
+        msg: M,
+    ) -> Self {
+        if let Err(err) = parse_keys(keys).and_then(|keys| self.add_binding(keys, msg)) {
+            panic!("Invalid key sequence `{}`: {}", keys, err);
+        }
+        self
+    }
+
+    /// Bind `keys` to `msg`, binding for same keys is replaced
+    ///
+    /// A sequence can't be a prefix of another one e.g. `g` and `g g` as the longer one could
+    /// never be reached.
+    pub fn add_binding(
+        &mut self,
+        keys: impl Into<Vec<KeyEvent>>,
+        msg: M,
+    ) -> Result<(), KeyMapErrorKind> {
+        let keys = normalize_keys(keys.into());
+
+        match check_conflict(self.bindings.iter(), &keys) {
+            Some(kind) => Err(kind),
+            None => {
+                self.insert(keys, msg);
+                Ok(())
+            }
+        }
+    }
+
+    fn insert(
+        &mut self,
+        keys: Vec<KeyEvent>,
+        msg: M,
+    ) {
+        self.bindings.retain(|(k, _)| *k != keys);
+        self.bindings.push((keys, msg));
+    }
+
+    pub fn remove_binding(
+        &mut self,
+        keys: &[KeyEvent],
+    ) -> Option<M> {
+        let pos = self.bindings.iter().position(|(k, _)| k == keys)?;
+        Some(self.bindings.remove(pos).1)
+    }
+
+    #[inline]
+    pub fn bindings(&self) -> impl Iterator<Item = (&[KeyEvent], &M)> {
+        self.bindings.iter().map(|(k, m)| (&k[..], m))
+    }
+
+    pub fn lookup(
+        &self,
+        keys: &[KeyEvent],
+    ) -> KeyLookup<'_, M> {
+        let mut ret = KeyLookup::None;
+
+        for (binding, msg) in self.bindings.iter() {
+            if binding.len() < keys.len() {
+                continue;
+            }
+
+            if binding
+                .iter()
+                .zip(keys.iter())
+                .all(|(b, k)| *b == normalize(*k))
+            {
+                if binding.len() == keys.len() {
+                    return KeyLookup::Match(msg);
+                } else {
+                    ret = KeyLookup::Prefix;
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+/// Terminals report some keys differently, make them comparable
+///
+/// Letters with `Shift` are uppercase and uppercase letters have `Shift`, other chars already
+/// contain shift so it is removed from them.
+#[inline]
+fn normalize(mut key: KeyEvent) -> KeyEvent {
+    match key.code {
+        KeyCode::Char(ch) if ch.is_lowercase() || ch.is_uppercase() => {
+            if key.modifiers.contains(KeyModifier::Shift) {
+                key.code = KeyCode::Char(to_upper(ch));
+            } else if ch.is_uppercase() {
+                key.modifiers.insert(KeyModifier::Shift);
+            }
+        }
+        KeyCode::Char(_) => {
+            key.modifiers.remove(KeyModifier::Shift);
+        }
+        KeyCode::BackTab => {
+            key.code = KeyCode::Tab;
+            key.modifiers.insert(KeyModifier::Shift);
+        }
+        _ => {}
+    }
+
+    key
+}
+
+/// Uppercase of `ch` when it is a single char
+#[inline]
+fn to_upper(ch: char) -> char {
+    let mut upper = ch.to_uppercase();
+
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => ch,
+    }
+}
+
+#[inline]
+fn normalize_keys(mut keys: Vec<KeyEvent>) -> Vec<KeyEvent> {
+    for key in keys.iter_mut() {
+        *key = normalize(*key);
+    }
+    keys
+}
+
+/// Error when `keys` and one of `bindings` is a prefix of the other
+fn check_conflict<'a, M: 'a>(
+    mut bindings: impl Iterator<Item = &'a (Vec<KeyEvent>, M)>,
+    keys: &[KeyEvent],
+) -> Option<KeyMapErrorKind> {
+    let (other, _) = bindings.find(|(other, _)| {
+        other.len() != keys.len() && (other.starts_with(keys) || keys.starts_with(other))
+    })?;
+
+    Some(KeyMapErrorKind::PrefixConflict(
+        format_keys(keys),
+        format_keys(other),
+    ))
+}
+
+fn format_keys(keys: &[KeyEvent]) -> String {
+    keys.iter()
+        .map(KeyEvent::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse whitespace separated key sequence
+///
+/// Each key is `modifier-...-key` where modifiers are `ctrl`(`c`), `alt`(`a`, `meta`, `m`)
+/// and `shift`(`s`). Key is single char or one of `enter`, `tab`, `backtab`, `backspace`,
+/// `esc`, `space`, `up`, `down`, `left`, `right`, `home`, `end`, `pageup`, `pagedown`,
+/// `delete`, `insert` and `f1`..`f12`. Names are case insensitive.
+pub fn parse_keys(keys: &str) -> Result<Vec<KeyEvent>, KeyMapErrorKind> {
+    let keys = keys
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<Vec<KeyEvent>, _>>()?;
+
+    if keys.is_empty() {
+        Err(KeyMapErrorKind::EmptyKeys)
+    } else {
+        Ok(keys)
+    }
+}
+
+impl FromStr for KeyEvent {
+    type Err = KeyMapErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // `-` itself can be a key like `ctrl--`
+        let (modifiers, key) = if s.len() > 1 && s.ends_with("--") {
+            (&s[..s.len() - 2], "-")
+        } else if s == "-" {
+            ("", "-")
+        } else {
+            match s.rfind('-') {
+                Some(pos) => (&s[..pos], &s[pos + 1..]),
+                None => ("", s),
+            }
+        };
+
+        let mut ret = KeyEvent::new(parse_code(key)?, KeyModifiers::empty());
+
+        for modifier in modifiers.split('-').filter(|m| !m.is_empty()) {
+            ret.modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "c" | "control" => KeyModifier::Control,
+                "alt" | "a" | "meta" | "m" => KeyModifier::Alt,
+                "shift" | "s" => KeyModifier::Shift,
+                _ => return Err(KeyMapErrorKind::UnknownModifier(modifier.into())),
+            };
+        }
+
+        Ok(ret)
+    }
+}
+
+fn parse_code(key: &str) -> Result<KeyCode, KeyMapErrorKind> {
+    let mut chars = key.chars();
+
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Ok(KeyCode::Char(ch));
+    }
+
+    let code = match key.to_ascii_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" | "bs" => KeyCode::Backspace,
+        "esc" | "escape" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        lower => {
+            match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                _ => return Err(KeyMapErrorKind::UnknownKey(key.into())),
+            }
+        }
+    };
+
+    Ok(code)
+}
+
+impl fmt::Display for KeyEvent {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        for modifier in self.modifiers.iter() {
+            f.write_str(match modifier {
+                KeyModifier::Control => "Ctrl-",
+                KeyModifier::Alt => "Alt-",
+                KeyModifier::Shift => "Shift-",
+            })?;
+        }
+
+        match self.code {
+            KeyCode::Backspace => f.write_str("Backspace"),
+            KeyCode::Enter => f.write_str("Enter"),
+            KeyCode::Left => f.write_str("Left"),
+            KeyCode::Right => f.write_str("Right"),
+            KeyCode::Up => f.write_str("Up"),
+            KeyCode::Down => f.write_str("Down"),
+            KeyCode::Home => f.write_str("Home"),
+            KeyCode::End => f.write_str("End"),
+            KeyCode::PageUp => f.write_str("PageUp"),
+            KeyCode::PageDown => f.write_str("PageDown"),
+            KeyCode::Tab => f.write_str("Tab"),
+            KeyCode::BackTab => f.write_str("BackTab"),
+            KeyCode::Delete => f.write_str("Delete"),
+            KeyCode::Insert => f.write_str("Insert"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(ch) => write!(f, "{}", ch),
+            KeyCode::Null => f.write_str("Null"),
+            KeyCode::Esc => f.write_str("Esc"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeyMapErrorKind {
+    EmptyKeys,
+    UnknownKey(String),
+    UnknownModifier(String),
+    MissingAction,
+    UnknownAction(String),
+    /// One key sequence is a prefix of the other
+    PrefixConflict(String, String),
+}
+
+impl fmt::Display for KeyMapErrorKind {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            KeyMapErrorKind::EmptyKeys => f.write_str("empty key sequence"),
+            KeyMapErrorKind::UnknownKey(key) => write!(f, "unknown key `{}`", key),
+            KeyMapErrorKind::UnknownModifier(m) => write!(f, "unknown modifier `{}`", m),
+            KeyMapErrorKind::MissingAction => f.write_str("expected `keys = action`"),
+            KeyMapErrorKind::UnknownAction(action) => write!(f, "unknown action `{}`", action),
+            KeyMapErrorKind::PrefixConflict(keys, other) => {
+                write!(f, "`{}` conflicts with `{}`", keys, other)
+            }
+        }
+    }
+}
+
+impl Error for KeyMapErrorKind {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyMapError {
+    pub line: usize,
+    pub kind: KeyMapErrorKind,
+}
+
+impl KeyMapError {
+    #[inline]
+    pub fn new(
+        line: usize,
+        kind: KeyMapErrorKind,
+    ) -> Self {
+        Self { line, kind }
+    }
+}
+
+impl fmt::Display for KeyMapError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl Error for KeyMapError {}
+
+#[test]
+fn parse_keys_test() {
+    assert_eq!(
+        parse_keys("ctrl-x C-s"),
+        Ok(vec![
+            KeyEvent::new(KeyCode::Char('x'), KeyModifier::Control),
+            KeyEvent::new(KeyCode::Char('s'), KeyModifier::Control),
+        ])
+    );
+    assert_eq!(
+        parse_keys("g g"),
+        Ok(vec![KeyCode::Char('g').into(), KeyCode::Char('g').into(),])
+    );
+    assert_eq!(
+        parse_keys("shift-tab alt-F5 ctrl--"),
+        Ok(vec![
+            KeyEvent::new(KeyCode::Tab, KeyModifier::Shift),
+            KeyEvent::new(KeyCode::F(5), KeyModifier::Alt),
+            KeyEvent::new(KeyCode::Char('-'), KeyModifier::Control),
+        ])
+    );
+    assert_eq!(
+        parse_keys("hyper-x"),
+        Err(KeyMapErrorKind::UnknownModifier("hyper".into()))
+    );
+    assert_eq!(
+        parse_keys("ctrl-foo"),
+        Err(KeyMapErrorKind::UnknownKey("foo".into()))
+    );
+    assert_eq!(parse_keys("  "), Err(KeyMapErrorKind::EmptyKeys));
+}
+
+#[test]
+fn lookup_test() {
+    let map = KeyMap::new()
+        .bind("g g", 1)
+        .bind("G", 2)
+        .bind("shift-tab", 3);
+
+    let g = KeyEvent::from(KeyCode::Char('g'));
+    let shift_g = KeyEvent::new(KeyCode::Char('G'), KeyModifier::Shift);
+    let back_tab = KeyEvent::new(KeyCode::BackTab, KeyModifier::Shift);
+
+    assert_eq!(map.lookup(&[g]), KeyLookup::Prefix);
+    assert_eq!(map.lookup(&[g, g]), KeyLookup::Match(&1));
+    assert_eq!(map.lookup(&[g, shift_g]), KeyLookup::None);
+    assert_eq!(map.lookup(&[shift_g]), KeyLookup::Match(&2));
+    assert_eq!(map.lookup(&[back_tab]), KeyLookup::Match(&3));
+}
+
+#[test]
+fn shift_test() {
+    let map = KeyMap::new().bind("shift-a", 1).bind("b", 2).bind("?", 3);
+
+    let a = KeyEvent::from(KeyCode::Char('a'));
+    let shift_a = KeyEvent::new(KeyCode::Char('A'), KeyModifier::Shift);
+    let b = KeyEvent::from(KeyCode::Char('b'));
+    let shift_b = KeyEvent::new(KeyCode::Char('B'), KeyModifier::Shift);
+    let question = KeyEvent::new(KeyCode::Char('?'), KeyModifier::Shift);
+
+    assert_eq!(map.lookup(&[a]), KeyLookup::None);
+    assert_eq!(map.lookup(&[shift_a]), KeyLookup::Match(&1));
+    assert_eq!(
+        map.lookup(&[KeyCode::Char('A').into()]),
+        KeyLookup::Match(&1)
+    );
+    assert_eq!(map.lookup(&[b]), KeyLookup::Match(&2));
+    assert_eq!(map.lookup(&[shift_b]), KeyLookup::None);
+    assert_eq!(map.lookup(&[question]), KeyLookup::Match(&3));
+}
+
+#[test]
+fn prefix_conflict_test() {
+    let mut map = KeyMap::new().bind("g g", 1);
+    let g = KeyEvent::from(KeyCode::Char('g'));
+
+    assert_eq!(
+        map.add_binding(vec![g], 2),
+        Err(KeyMapErrorKind::PrefixConflict("g".into(), "g g".into()))
+    );
+    assert_eq!(
+        map.add_binding(vec![g, g, g], 2),
+        Err(KeyMapErrorKind::PrefixConflict(
+            "g g g".into(),
+            "g g".into()
+        ))
+    );
+    assert_eq!(map.add_binding(vec![g, g], 3), Ok(()));
+    assert_eq!(map.lookup(&[g, g]), KeyLookup::Match(&3));
+
+    assert_eq!(
+        map.load_config("ctrl-x = cut\nctrl-x ctrl-s = save", |_| Some(4))
+            .unwrap_err(),
+        KeyMapError::new(
+            2,
+            KeyMapErrorKind::PrefixConflict("Ctrl-x Ctrl-s".into(), "Ctrl-x".into())
+        )
+    );
+    assert_eq!(map.bindings().count(), 1);
+}
+
+#[test]
+fn config_test() {
+    let config = "
+# save file
+ctrl-x ctrl-s = save
+ctrl-x ctrl-c = quit
+";
+    let mut map = KeyMap::from_config(config, |action| {
+        match action {
+            "save" => Some(1),
+            "quit" => Some(2),
+            _ => None,
+        }
+    })
+    .unwrap();
+
+    let ctrl_x = KeyEvent::new(KeyCode::Char('x'), KeyModifier::Control);
+    let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifier::Control);
+
+    assert_eq!(map.lookup(&[ctrl_x, ctrl_c]), KeyLookup::Match(&2));
+
+    map.load_config("ctrl-x ctrl-c = save", |_| Some(3))
+        .unwrap();
+    assert_eq!(map.lookup(&[ctrl_x, ctrl_c]), KeyLookup::Match(&3));
+
+    assert_eq!(
+        KeyMap::from_config("\nctrl-q = exit", |_| None::<u32>).unwrap_err(),
+        KeyMapError::new(2, KeyMapErrorKind::UnknownAction("exit".into()))
+    );
+    assert_eq!(
+        KeyMap::from_config("ctrl-q", |_| Some(())).unwrap_err(),
+        KeyMapError::new(1, KeyMapErrorKind::MissingAction)
+    );
+}
@@ -0,0 +1,225 @@
+/// Size constraint of a child along the main axis of a container
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Constraint {
+    /// Use desired size of child
+    #[default]
+    Auto,
+    /// Exactly given cells
+    Fixed(u16),
+    /// Desired size but at least given cells
+    Min(u16),
+    /// Desired size but at most given cells
+    Max(u16),
+    /// Percentage of available space
+    Percentage(u16),
+    /// `a / b` of available space
+    Ratio(u16, u16),
+    /// Share of space left by other children, weighted by given value
+    Fill(u16),
+}
+
+impl Constraint {
+    /// Size this constraint asks for when nothing is known about available space
+    pub fn desired(
+        self,
+        desired: u16,
+    ) -> u16 {
+        match self {
+            Constraint::Fixed(n) => n,
+            Constraint::Min(n) => desired.max(n),
+            Constraint::Max(n) => desired.min(n),
+            Constraint::Auto
+            | Constraint::Percentage(_)
+            | Constraint::Ratio(..)
+            | Constraint::Fill(_) => desired,
+        }
+    }
+
+    fn base(
+        self,
+        desired: u16,
+        available: u16,
+    ) -> u16 {
+        match self {
+            Constraint::Percentage(p) => (available as u32 * p.min(100) as u32 / 100) as u16,
+            Constraint::Ratio(_, 0) => 0,
+            Constraint::Ratio(a, b) => (available as u32 * a.min(b) as u32 / b as u32) as u16,
+            Constraint::Fill(_) => 0,
+            _ => self.desired(desired),
+        }
+    }
+
+    /// Size this constraint can shrink to before others lose their space
+    fn lower_bound(self) -> u16 {
+        match self {
+            Constraint::Fixed(n) | Constraint::Min(n) => n,
+            _ => 0,
+        }
+    }
+}
+
+/// Placement of a child on the cross axis of a container
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Alignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// Use whole cross axis
+    Stretch,
+}
+
+impl Alignment {
+    /// Returns offset and size of child in cross axis
+    pub fn place(
+        self,
+        desired: u16,
+        available: u16,
+    ) -> (u16, u16) {
+        let size = desired.min(available);
+        let rest = available - size;
+
+        match self {
+            Alignment::Start => (0, size),
+            Alignment::Center => (rest / 2, size),
+            Alignment::End => (rest, size),
+            Alignment::Stretch => (0, available),
+        }
+    }
+}
+
+/// Horizontal alignment of text lines
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    /// Stretch spaces between words to fill the width, last line of paragraph is left aligned
+    Justify,
+}
+
+/// Distribute `available` cells between children
+///
+/// `items` is pair of constraint and desired size of each child.
+/// When space is not enough, children are shrunk from the last one; first down to their
+/// lower bound(`Fixed`, `Min`), then down to zero.
+/// Space left after that goes to `Fill` children by weight.
+pub fn solve(
+    items: &[(Constraint, u16)],
+    available: u16,
+) -> Vec<u16> {
+    let mut sizes: Vec<u16> = items
+        .iter()
+        .map(|&(constraint, desired)| constraint.base(desired, available))
+        .collect();
+
+    let mut total: u32 = sizes.iter().map(|&s| s as u32).sum();
+
+    if total > available as u32 {
+        let mut over = total - available as u32;
+
+        for pass in 0..2 {
+            for (size, &(constraint, _)) in sizes.iter_mut().zip(items).rev() {
+                if over == 0 {
+                    break;
+                }
+
+                let bound = if pass == 0 {
+                    constraint.lower_bound()
+                } else {
+                    0
+                };
+                let shrink = (size.saturating_sub(bound) as u32).min(over);
+                *size -= shrink as u16;
+                over -= shrink;
+            }
+        }
+
+        total = available as u32;
+    }
+
+    let mut rest = available as u32 - total;
+    let weight: u32 = items
+        .iter()
+        .map(|&(constraint, _)| {
+            match constraint {
+                Constraint::Fill(w) => w as u32,
+                _ => 0,
+            }
+        })
+        .sum();
+
+    if weight == 0 {
+        return sizes;
+    }
+
+    let space = rest;
+    for (size, &(constraint, _)) in sizes.iter_mut().zip(items) {
+        if let Constraint::Fill(w) = constraint {
+            let share = space * w as u32 / weight;
+            *size += share as u16;
+            rest -= share;
+        }
+    }
+
+    // Give remainder to first fill children
+    for (size, &(constraint, _)) in sizes.iter_mut().zip(items) {
+        if rest == 0 {
+            break;
+        }
+
+        if let Constraint::Fill(w) = constraint {
+            if w > 0 {
+                *size += 1;
+                rest -= 1;
+            }
+        }
+    }
+
+    sizes
+}
+
+#[test]
+fn solve_auto_test() {
+    use Constraint::*;
+    assert_eq!(solve(&[(Auto, 3), (Auto, 4)], 10), [3, 4]);
+    assert_eq!(solve(&[(Auto, 3), (Auto, 4)], 5), [3, 2]);
+    assert_eq!(solve(&[(Auto, 3), (Auto, 4), (Auto, 1)], 2), [2, 0, 0]);
+}
+
+#[test]
+fn solve_fill_test() {
+    use Constraint::*;
+    assert_eq!(solve(&[(Fixed(2), 10), (Fill(1), 0)], 10), [2, 8]);
+    assert_eq!(solve(&[(Fill(1), 0), (Fill(2), 0)], 10), [4, 6]);
+    assert_eq!(solve(&[(Fill(1), 0), (Fill(1), 0), (Fill(1), 0)], 10), [
+        4, 3, 3
+    ]);
+}
+
+#[test]
+fn solve_ratio_test() {
+    use Constraint::*;
+    assert_eq!(solve(&[(Percentage(30), 0), (Percentage(70), 0)], 20), [
+        6, 14
+    ]);
+    assert_eq!(solve(&[(Ratio(1, 3), 0), (Fill(1), 0)], 9), [3, 6]);
+}
+
+#[test]
+fn solve_bound_test() {
+    use Constraint::*;
+    assert_eq!(solve(&[(Auto, 5), (Min(3), 1)], 5), [2, 3]);
+    assert_eq!(solve(&[(Max(2), 5), (Auto, 5)], 10), [2, 5]);
+    assert_eq!(solve(&[(Fixed(4), 0), (Fixed(4), 0)], 6), [4, 2]);
+}
+
+#[test]
+fn alignment_test() {
+    assert_eq!(Alignment::Start.place(2, 5), (0, 2));
+    assert_eq!(Alignment::Center.place(2, 5), (1, 2));
+    assert_eq!(Alignment::End.place(2, 5), (3, 2));
+    assert_eq!(Alignment::Stretch.place(2, 5), (0, 5));
+    assert_eq!(Alignment::End.place(7, 5), (0, 5));
+}
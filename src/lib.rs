@@ -1,15 +1,21 @@
 pub mod macros;
 
+pub mod ansi;
 pub mod backend;
 pub mod converters;
 pub mod event;
 pub mod executor;
+pub mod focus;
+pub mod keymap;
+pub mod layout;
+pub mod markup;
 pub mod never;
 pub mod orientation;
 pub mod printer;
 pub mod rect;
 pub mod state;
 pub mod style;
+pub mod styled_text;
 pub mod vec2;
 pub mod view;
 pub mod view_wrappers;
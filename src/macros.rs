@@ -21,6 +21,34 @@ macro_rules! impl_view_with_inner {
         ) {
             self.$inner.layout(size);
         }
+
+        #[inline]
+        fn take_focus(
+            &mut self,
+            direction: $crate::focus::FocusDirection,
+        ) -> bool {
+            self.$inner.take_focus(direction)
+        }
+
+        #[inline]
+        fn has_focus(&self) -> bool {
+            self.$inner.has_focus()
+        }
+
+        #[inline]
+        fn release_focus(&mut self) {
+            self.$inner.release_focus();
+        }
+
+        #[inline]
+        fn has_pending_keys(&self) -> bool {
+            self.$inner.has_pending_keys()
+        }
+
+        #[inline]
+        fn focus_rect(&self) -> Option<$crate::rect::Rect> {
+            self.$inner.focus_rect()
+        }
     };
 }
 
@@ -0,0 +1,406 @@
+//! Inline markup for [`StyledText`]
+//!
+//! ```text
+//! [b]bold[/b] [fg=title]title[/] [reverse,red]x[/]
+//! ```
+//!
+//! A tag is a comma separated list of items:
+//!
+//! * Effects: `b`/`bold`, `dim`, `i`/`italic`, `u`/`underline`, `blink`, `reverse`, `hidden`,
+//!   `s`/`strike`
+//! * `fg=<color>`, `bg=<color>`, a bare color is a foreground color
+//!
+//! Colors are palette names(`primary`, `title`, `highlight`, ...), ansi names(`red`, `cyan`, ...),
+//! 256 color numbers(`208`) or `#rrggbb`. Names are case insensitive.
+//!
+//! `[/]` closes the last tag, `[/name]` does the same but checks that `name` is one of its items.
+//! Aliases and keys are accepted there so `[bold]` is closed by `[/b]` and `[fg=red]` by
+//! `[/fg]`.
+//! Tags left open are closed at the end of text.
+//! `\[` and `\\` are escapes for `[` and `\`.
+
+use crate::{
+    style::{
+        AnsiColor,
+        Color,
+        Effect,
+        PaletteColor,
+        Style,
+    },
+    styled_text::StyledText,
+};
+use std::{
+    error::Error,
+    fmt,
+    str::FromStr,
+};
+
+pub fn parse(markup: &str) -> Result<StyledText, MarkupError> {
+    let mut text = StyledText::new();
+    // Open tag and style before it
+    let mut stack: Vec<(&str, Style)> = Vec::new();
+    let mut style = Style::default();
+    let mut buf = String::new();
+    let mut pos = 0;
+
+    while let Some(ch) = markup[pos..].chars().next() {
+        match ch {
+            '\\' => {
+                pos += 1;
+                match markup[pos..].chars().next() {
+                    Some(escaped) if escaped == '[' || escaped == '\\' => {
+                        buf.push(escaped);
+                        pos += 1;
+                    }
+                    _ => buf.push('\\'),
+                }
+            }
+            '[' => {
+                let end = match markup[pos..].find(']') {
+                    Some(end) => pos + end,
+                    None => return Err(MarkupError::new(pos, MarkupErrorKind::UnclosedBracket)),
+                };
+                let tag = markup[pos + 1..end].trim();
+
+                text.append_styled(&buf, style);
+                buf.clear();
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    let name = name.trim();
+                    let unmatched =
+                        || MarkupError::new(pos, MarkupErrorKind::UnmatchedClose(name.into()));
+
+                    let (open, prev) = stack.pop().ok_or_else(unmatched)?;
+
+                    if !name.is_empty() && !open.split(',').any(|item| closes(item, name)) {
+                        return Err(unmatched());
+                    }
+
+                    style = prev;
+                } else {
+                    let new_style =
+                        apply_tag(style, tag).map_err(|kind| MarkupError::new(pos, kind))?;
+                    stack.push((tag, style));
+                    style = new_style;
+                }
+
+                pos = end + 1;
+            }
+            ch => {
+                buf.push(ch);
+                pos += ch.len_utf8();
+            }
+        }
+    }
+
+    text.append_styled(&buf, style);
+    Ok(text)
+}
+
+/// Whether `[/name]` closes tag `item`
+fn closes(
+    item: &str,
+    name: &str,
+) -> bool {
+    let item = item.trim().to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+
+    if let Some((key, value)) = item.split_once('=') {
+        return key.trim() == name || value.trim() == name;
+    }
+
+    match (parse_effect(&item), parse_effect(&name)) {
+        (Some(open), Some(close)) => open == close,
+        _ => item == name || (name == "fg" && parse_color(&item).is_ok()),
+    }
+}
+
+fn apply_tag(
+    mut style: Style,
+    tag: &str,
+) -> Result<Style, MarkupErrorKind> {
+    if tag.is_empty() {
+        return Err(MarkupErrorKind::EmptyTag);
+    }
+
+    for item in tag.split(',').map(str::trim) {
+        if let Some((key, value)) = item.split_once('=') {
+            match key.trim().to_ascii_lowercase().as_str() {
+                "fg" => style.fg = parse_color(value.trim())?,
+                "bg" => style.bg = parse_color(value.trim())?,
+                _ => return Err(MarkupErrorKind::UnknownTag(item.into())),
+            }
+        } else if let Some(effect) = parse_effect(item) {
+            style.effects |= effect;
+        } else if let Ok(color) = parse_color(item) {
+            style.fg = color;
+        } else if item.is_empty() {
+            return Err(MarkupErrorKind::EmptyTag);
+        } else {
+            return Err(MarkupErrorKind::UnknownTag(item.into()));
+        }
+    }
+
+    Ok(style)
+}
+
+fn parse_effect(name: &str) -> Option<Effect> {
+    let effect = match name.to_ascii_lowercase().as_str() {
+        "b" | "bold" => Effect::Bold,
+        "dim" => Effect::Dim,
+        "i" | "italic" => Effect::Italic,
+        "u" | "underline" => Effect::Underline,
+        "blink" => Effect::Blink,
+        "reverse" => Effect::Reverse,
+        "hidden" => Effect::Hidden,
+        "s" | "strike" => Effect::StrikeThrough,
+        _ => return None,
+    };
+
+    Some(effect)
+}
+
+pub fn parse_color(name: &str) -> Result<Color, MarkupErrorKind> {
+    let unknown = || MarkupErrorKind::UnknownColor(name.into());
+
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(unknown());
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| unknown());
+        return Ok(Color::Ansi(Some(AnsiColor::RGB(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+        ))));
+    }
+
+    if let Ok(n) = name.parse::<u8>() {
+        return Ok(Color::Ansi(Some(AnsiColor::Fixed(n))));
+    }
+
+    let color = match name.to_ascii_lowercase().as_str() {
+        "background" => Color::Palette(PaletteColor::Background),
+        "view" => Color::Palette(PaletteColor::View),
+        "primary" => Color::Palette(PaletteColor::Primary),
+        "title" => Color::Palette(PaletteColor::Title),
+        "highlight" => Color::Palette(PaletteColor::Highlight),
+        "highlightinactive" | "highlight_inactive" => {
+            Color::Palette(PaletteColor::HighlightInactive)
+        }
+        "secondary" => Color::Palette(PaletteColor::Secondary),
+        "error" => Color::Palette(PaletteColor::Error),
+        "black" => Color::Ansi(Some(AnsiColor::Black)),
+        "red" => Color::Ansi(Some(AnsiColor::Red)),
+        "green" => Color::Ansi(Some(AnsiColor::Green)),
+        "yellow" => Color::Ansi(Some(AnsiColor::Yellow)),
+        "blue" => Color::Ansi(Some(AnsiColor::Blue)),
+        "purple" | "magenta" => Color::Ansi(Some(AnsiColor::Purple)),
+        "cyan" => Color::Ansi(Some(AnsiColor::Cyan)),
+        "white" => Color::Ansi(Some(AnsiColor::White)),
+        _ => return Err(unknown()),
+    };
+
+    Ok(color)
+}
+
+impl FromStr for StyledText {
+    type Err = MarkupError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MarkupErrorKind {
+    UnclosedBracket,
+    EmptyTag,
+    UnknownTag(String),
+    UnknownColor(String),
+    UnmatchedClose(String),
+}
+
+impl fmt::Display for MarkupErrorKind {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            MarkupErrorKind::UnclosedBracket => f.write_str("`[` without `]`"),
+            MarkupErrorKind::EmptyTag => f.write_str("empty tag"),
+            MarkupErrorKind::UnknownTag(tag) => write!(f, "unknown tag `{}`", tag),
+            MarkupErrorKind::UnknownColor(color) => write!(f, "unknown color `{}`", color),
+            MarkupErrorKind::UnmatchedClose(tag) => write!(f, "`[/{}]` has no open tag", tag),
+        }
+    }
+}
+
+impl Error for MarkupErrorKind {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarkupError {
+    /// Byte offset of the tag
+    pub pos:  usize,
+    pub kind: MarkupErrorKind,
+}
+
+impl MarkupError {
+    #[inline]
+    pub fn new(
+        pos: usize,
+        kind: MarkupErrorKind,
+    ) -> Self {
+        Self { pos, kind }
+    }
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        write!(f, "offset {}: {}", self.pos, self.kind)
+    }
+}
+
+impl Error for MarkupError {}
+
+#[test]
+fn markup_parse_test() {
+    let bold = Style::default().effects(Effect::Bold);
+    let title = Style::default().fg(Color::Palette(PaletteColor::Title));
+    let red_reverse = Style::default()
+        .fg(Color::Ansi(Some(AnsiColor::Red)))
+        .effects(Effect::Reverse);
+
+    assert_eq!(
+        parse("[b]bold[/b] [fg=Title]title[/] [reverse,red]x[/]"),
+        Ok(StyledText::new()
+            .span("bold", bold)
+            .span(" ", Style::default())
+            .span("title", title)
+            .span(" ", Style::default())
+            .span("x", red_reverse))
+    );
+}
+
+#[test]
+fn markup_nested_test() {
+    let bold = Style::default().effects(Effect::Bold);
+    let bold_rgb = bold.fg(Color::Ansi(Some(AnsiColor::RGB(255, 0, 16))));
+
+    assert_eq!(
+        parse("[b]a[#ff0010]b[/]c"),
+        Ok(StyledText::new()
+            .span("a", bold)
+            .span("b", bold_rgb)
+            .span("c", bold))
+    );
+}
+
+#[test]
+fn markup_close_alias_test() {
+    let bold = Style::default().effects(Effect::Bold);
+    let red = Style::default().fg(Color::Ansi(Some(AnsiColor::Red)));
+
+    for markup in &[
+        "[bold]x[/b]y",
+        "[B]x[/Bold]y",
+        "[b,fg=red]x[/FG]y",
+        "[red]x[/fg]y",
+        "[fg=red]x[/red]y",
+    ] {
+        let text = parse(markup).unwrap();
+        assert_eq!(
+            text.spans().last(),
+            Some(("y", Style::default())),
+            "{}",
+            markup
+        );
+    }
+
+    assert_eq!(
+        parse("[fg=red]x[/fg]"),
+        Ok(StyledText::new().span("x", red))
+    );
+    assert_eq!(parse("[bold]x[/b]"), Ok(StyledText::new().span("x", bold)));
+}
+
+#[test]
+fn markup_key_case_space_test() {
+    let red_on_blue = Style::default()
+        .fg(Color::Ansi(Some(AnsiColor::Red)))
+        .bg(Color::Ansi(Some(AnsiColor::Blue)));
+
+    for markup in &["[FG=red,Bg=blue]x[/]", "[fg = red, bg = blue]x[/]"] {
+        assert_eq!(
+            parse(markup),
+            Ok(StyledText::new().span("x", red_on_blue)),
+            "{}",
+            markup
+        );
+    }
+
+    assert_eq!(
+        parse("[ul=red]"),
+        Err(MarkupError::new(
+            0,
+            MarkupErrorKind::UnknownTag("ul=red".into())
+        ))
+    );
+}
+
+#[test]
+fn markup_escape_test() {
+    assert_eq!(parse(r"\[b] \\ \n"), Ok(StyledText::plain(r"[b] \ \n")));
+}
+
+#[test]
+fn markup_error_test() {
+    assert_eq!(
+        parse("ab[b"),
+        Err(MarkupError::new(2, MarkupErrorKind::UnclosedBracket))
+    );
+    assert_eq!(
+        parse("[bold,foo]"),
+        Err(MarkupError::new(
+            0,
+            MarkupErrorKind::UnknownTag("foo".into())
+        ))
+    );
+    assert_eq!(
+        parse("[fg=nope]"),
+        Err(MarkupError::new(
+            0,
+            MarkupErrorKind::UnknownColor("nope".into())
+        ))
+    );
+    assert_eq!(
+        parse("[b]x[/i]"),
+        Err(MarkupError::new(
+            4,
+            MarkupErrorKind::UnmatchedClose("i".into())
+        ))
+    );
+    assert_eq!(
+        parse("[fg=red]x[/bg]"),
+        Err(MarkupError::new(
+            9,
+            MarkupErrorKind::UnmatchedClose("bg".into())
+        ))
+    );
+    assert_eq!(
+        parse("[/]"),
+        Err(MarkupError::new(
+            0,
+            MarkupErrorKind::UnmatchedClose("".into())
+        ))
+    );
+    assert_eq!(
+        parse("[]"),
+        Err(MarkupError::new(0, MarkupErrorKind::EmptyTag))
+    );
+}
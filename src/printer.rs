@@ -1,6 +1,7 @@
 use crate::{
     backend::{
         Backend,
+        DimmedBackend,
         SlicedBackend,
     },
     rect::Rect,
@@ -8,12 +9,16 @@ use crate::{
         Style,
         Theme,
     },
+    styled_text::StyledText,
     vec2::Vec2,
 };
 use std::mem::swap;
+use unicode_width::UnicodeWidthStr;
 
 pub struct Printer<'a> {
     bound:   Rect,
+    /// Cells at top-left of `bound` which are hidden by [`Printer::sliced`]
+    hidden:  Vec2,
     style:   Style,
     backend: &'a mut dyn Backend,
     theme:   &'a Theme,
@@ -26,24 +31,24 @@ impl<'a> Printer<'a> {
     ) -> Self {
         Self {
             bound: Rect::new((0, 0), backend.size()),
+            hidden: Vec2::new(0, 0),
             style: Style::default(),
             backend,
             theme,
         }
     }
 
+    /// Call `f` with content moved up and left by `pos`, it is cut at current bound
     pub fn sliced<T>(
         &mut self,
         pos: impl Into<Vec2>,
         f: impl FnOnce(&mut Printer) -> T,
     ) -> T {
         let pos = pos.into();
-        let mut backend = SlicedBackend::new(self.backend, pos);
+        let mut backend = SlicedBackend::with_start(self.backend, pos, self.bound.start());
         let mut printer = Printer {
-            bound:   Rect::new(
-                self.bound.start().saturating_sub(pos),
-                self.bound.size() + pos,
-            ),
+            bound:   Rect::new(self.bound.start(), self.bound.size() + pos),
+            hidden:  self.hidden + pos,
             style:   self.style,
             backend: &mut backend,
             theme:   self.theme,
@@ -51,14 +56,45 @@ impl<'a> Printer<'a> {
         f(&mut printer)
     }
 
+    /// Everything printed by `f` gets dim effect
+    pub fn dimmed<T>(
+        &mut self,
+        f: impl FnOnce(&mut Printer) -> T,
+    ) -> T {
+        let old_style = self.backend.style();
+        let mut backend = DimmedBackend::new(self.backend);
+        backend.set_style(old_style);
+        let mut printer = Printer {
+            bound:   self.bound,
+            hidden:  self.hidden,
+            style:   self.style,
+            backend: &mut backend,
+            theme:   self.theme,
+        };
+        let ret = f(&mut printer);
+        self.backend.set_style(old_style);
+        ret
+    }
+
+    /// Call `f` with `bound`, it is cut at the end of current bound
     pub fn with_bound<T>(
         &mut self,
         mut bound: Rect,
         f: impl FnOnce(&mut Self) -> T,
     ) -> T {
+        let end = bound.end();
+        let max_end = self.bound.end();
+        let end = Vec2::new(end.x.min(max_end.x), end.y.min(max_end.y));
+        bound = Rect::new(bound.start(), end.saturating_sub(bound.start()));
+
+        let visible_start = self.bound.start() + self.hidden;
+        let mut hidden = visible_start.saturating_sub(bound.start());
+
         swap(&mut self.bound, &mut bound);
+        swap(&mut self.hidden, &mut hidden);
         let ret = f(self);
         swap(&mut self.bound, &mut bound);
+        swap(&mut self.hidden, &mut hidden);
         ret
     }
 
@@ -91,6 +127,17 @@ impl<'a> Printer<'a> {
         self.bound
     }
 
+    /// Part of the bound which is shown, relative to its start
+    ///
+    /// Smaller than the bound inside [`Printer::sliced`], views with many rows can skip the
+    /// rest.
+    #[inline]
+    pub fn visible(&self) -> Rect {
+        let size = self.bound.size();
+        let hidden = Vec2::new(self.hidden.x.min(size.x), self.hidden.y.min(size.y));
+        Rect::new(hidden, size - hidden)
+    }
+
     pub fn print(
         &mut self,
         start: impl Into<Vec2>,
@@ -98,12 +145,12 @@ impl<'a> Printer<'a> {
     ) {
         let start = start.into();
 
-        if !self.bound.contains_inclusive(start + self.bound.start()) {
+        if start.x > self.bound.w() || start.y >= self.bound.h() {
             return;
         }
 
         let sub_str =
-            crate::util::slice_str_with_width(text, (self.bound.end().x - start.x) as usize).0;
+            crate::util::slice_str_with_width(text, (self.bound.w() - start.x) as usize).0;
         self.raw_print(start, sub_str);
     }
 
@@ -128,6 +175,36 @@ impl<'a> Printer<'a> {
         });
     }
 
+    /// Print spans one after another, each span style is combined with current style
+    pub fn print_spans<'s>(
+        &mut self,
+        start: impl Into<Vec2>,
+        spans: impl IntoIterator<Item = (&'s str, Style)>,
+    ) {
+        let mut start = start.into();
+        let base = self.style;
+
+        for (text, style) in spans {
+            if start.x >= self.bound.w() {
+                break;
+            }
+
+            self.with_style(base.combine(style), |printer| {
+                printer.print(start, text);
+            });
+            start.x = start.x.saturating_add(text.width() as u16);
+        }
+    }
+
+    #[inline]
+    pub fn print_styled_text(
+        &mut self,
+        start: impl Into<Vec2>,
+        text: &StyledText,
+    ) {
+        self.print_spans(start, text.spans());
+    }
+
     #[inline]
     pub fn print_vertical_line(
         &mut self,
@@ -205,12 +282,11 @@ impl<'a> Printer<'a> {
         const LEFT_BOTTOM: &str = "└";
         const RIGHT_BOTTOM: &str = "┘";
 
-        let start = self.bound.start();
-        let end = self.bound.end();
+        let end = self.bound.size().saturating_sub(Vec2::new(1, 1));
 
-        self.raw_print(start, LEFT_TOP);
-        self.raw_print((end.x, start.y), RIGHT_TOP);
-        self.raw_print((start.x, end.y), LEFT_BOTTOM);
+        self.raw_print((0, 0), LEFT_TOP);
+        self.raw_print((end.x, 0), RIGHT_TOP);
+        self.raw_print((0, end.y), LEFT_BOTTOM);
         self.raw_print(end, RIGHT_BOTTOM);
     }
 }
@@ -22,13 +22,16 @@ pub enum PaletteColor {
     Title,
     Highlight,
     HighlightInactive,
+    /// Less important text like placeholders
+    Secondary,
+    Error,
     Custom(u8),
 }
 
 impl<T> Enum<T> for PaletteColor {
-    type Array = [T; 6 + 256];
+    type Array = [T; 8 + 256];
 
-    const POSSIBLE_VALUES: usize = 6 + 256;
+    const POSSIBLE_VALUES: usize = 8 + 256;
 
     #[inline]
     fn slice(array: &Self::Array) -> &[T] {
@@ -49,7 +52,9 @@ impl<T> Enum<T> for PaletteColor {
             3 => PaletteColor::Title,
             4 => PaletteColor::Highlight,
             5 => PaletteColor::HighlightInactive,
-            x => PaletteColor::Custom((x - 6) as u8),
+            6 => PaletteColor::Secondary,
+            7 => PaletteColor::Error,
+            x => PaletteColor::Custom((x - 8) as u8),
         }
     }
 
@@ -62,7 +67,9 @@ impl<T> Enum<T> for PaletteColor {
             PaletteColor::Title => 3,
             PaletteColor::Highlight => 4,
             PaletteColor::HighlightInactive => 5,
-            PaletteColor::Custom(labal) => labal as usize + 6,
+            PaletteColor::Secondary => 6,
+            PaletteColor::Error => 7,
+            PaletteColor::Custom(labal) => labal as usize + 8,
         }
     }
 
@@ -77,9 +84,11 @@ impl<T> Enum<T> for PaletteColor {
             (*arr.as_mut_ptr())[3] = f(PaletteColor::Title);
             (*arr.as_mut_ptr())[4] = f(PaletteColor::Highlight);
             (*arr.as_mut_ptr())[5] = f(PaletteColor::HighlightInactive);
+            (*arr.as_mut_ptr())[6] = f(PaletteColor::Secondary);
+            (*arr.as_mut_ptr())[7] = f(PaletteColor::Error);
 
             for i in 0..256 {
-                (*arr.as_mut_ptr())[i + 6] = f(PaletteColor::Custom(i as u8));
+                (*arr.as_mut_ptr())[i + 8] = f(PaletteColor::Custom(i as u8));
             }
 
             arr.assume_init()
@@ -87,7 +96,7 @@ impl<T> Enum<T> for PaletteColor {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Color {
     Ansi(Option<AnsiColor>),
     Palette(PaletteColor),
@@ -112,7 +121,7 @@ pub enum Effect {
     StrikeThrough,
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Style {
     pub fg:      Color,
     pub bg:      Color,
@@ -165,6 +174,47 @@ impl Style {
         }
     }
 
+    #[inline]
+    pub fn secondary() -> Self {
+        Self {
+            fg:      Color::Palette(PaletteColor::Secondary),
+            bg:      Color::Palette(PaletteColor::View),
+            effects: EnumSet::empty(),
+        }
+    }
+
+    #[inline]
+    pub fn error() -> Self {
+        Self {
+            fg:      Color::Palette(PaletteColor::Error),
+            bg:      Color::Palette(PaletteColor::View),
+            effects: EnumSet::empty(),
+        }
+    }
+
+    /// Put `top` over this style
+    ///
+    /// Colors of `top` which are `Color::Ansi(None)` are inherited from this style,
+    /// effects are merged.
+    #[inline]
+    pub fn combine(
+        self,
+        top: Style,
+    ) -> Self {
+        let pick = |base: Color, top: Color| {
+            match top {
+                Color::Ansi(None) => base,
+                top => top,
+            }
+        };
+
+        Self {
+            fg:      pick(self.fg, top.fg),
+            bg:      pick(self.bg, top.bg),
+            effects: self.effects | top.effects,
+        }
+    }
+
     #[inline]
     pub fn fg(
         mut self,
@@ -207,6 +257,8 @@ impl Default for Theme {
             PaletteColor::Title => Some(AnsiColor::Cyan),
             PaletteColor::Highlight => Some(AnsiColor::Yellow),
             PaletteColor::HighlightInactive => Some(AnsiColor::Black),
+            PaletteColor::Secondary => Some(AnsiColor::Fixed(8)),
+            PaletteColor::Error => Some(AnsiColor::Red),
             PaletteColor::Custom(_) => None,
         })
     }
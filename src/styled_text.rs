@@ -0,0 +1,200 @@
+use crate::style::Style;
+use std::ops::Range;
+use unicode_width::UnicodeWidthStr;
+
+/// Text made of spans which carry their own [`Style`]
+///
+/// Span style is combined with the style of printer with [`Style::combine`], so a span with
+/// `Style::default()` looks like plain text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledText {
+    source: String,
+    spans:  Vec<(Range<usize>, Style)>,
+}
+
+impl StyledText {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::styled(text, Style::default())
+    }
+
+    pub fn styled(
+        text: impl Into<String>,
+        style: Style,
+    ) -> Self {
+        let source = text.into();
+        let spans = if source.is_empty() {
+            Vec::new()
+        } else {
+            vec![(0..source.len(), style)]
+        };
+
+        Self { source, spans }
+    }
+
+    #[inline]
+    pub fn span(
+        mut self,
+        text: &str,
+        style: Style,
+    ) -> Self {
+        self.append_styled(text, style);
+        self
+    }
+
+    #[inline]
+    pub fn append(
+        &mut self,
+        text: &str,
+    ) {
+        self.append_styled(text, Style::default());
+    }
+
+    pub fn append_styled(
+        &mut self,
+        text: &str,
+        style: Style,
+    ) {
+        if text.is_empty() {
+            return;
+        }
+
+        let start = self.source.len();
+        self.source.push_str(text);
+        let end = self.source.len();
+
+        match self.spans.last_mut() {
+            Some((range, last)) if *last == style => range.end = end,
+            _ => self.spans.push((start..end, style)),
+        }
+    }
+
+    pub fn append_text(
+        &mut self,
+        text: &StyledText,
+    ) {
+        for (text, style) in text.spans() {
+            self.append_styled(text, style);
+        }
+    }
+
+    /// Text without styles
+    #[inline]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.source.width()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.source.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.source.is_empty()
+    }
+
+    pub fn spans(&self) -> impl Iterator<Item = (&str, Style)> {
+        self.spans
+            .iter()
+            .map(move |(range, style)| (&self.source[range.clone()], *style))
+    }
+
+    /// Spans in the byte `range` of source, spans on the boundary are cut
+    pub fn slice(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = (&str, Style)> {
+        self.spans.iter().filter_map(move |(span, style)| {
+            let start = span.start.max(range.start);
+            let end = span.end.min(range.end);
+
+            if start < end {
+                Some((&self.source[start..end], *style))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Owned copy of the byte `range` of source
+    pub fn sub(
+        &self,
+        range: Range<usize>,
+    ) -> Self {
+        let mut text = Self::new();
+
+        for (span, style) in self.slice(range) {
+            text.append_styled(span, style);
+        }
+
+        text
+    }
+
+    /// Split at `\n` like [`str::split`]
+    pub fn split_lines(&self) -> Vec<Self> {
+        let mut lines = Vec::new();
+        let mut start = 0;
+
+        for (i, _) in self.source.match_indices('\n') {
+            lines.push(self.sub(start..i));
+            start = i + 1;
+        }
+
+        lines.push(self.sub(start..self.source.len()));
+        lines
+    }
+}
+
+impl From<&str> for StyledText {
+    #[inline]
+    fn from(text: &str) -> Self {
+        Self::plain(text)
+    }
+}
+
+impl From<String> for StyledText {
+    #[inline]
+    fn from(text: String) -> Self {
+        Self::plain(text)
+    }
+}
+
+#[test]
+fn styled_text_slice_test() {
+    use crate::style::Effect;
+
+    let bold = Style::default().effects(Effect::Bold);
+    let text = StyledText::plain("ab").span("cd", bold).span("ef", bold);
+
+    assert_eq!(text.source(), "abcdef");
+    assert_eq!(text.spans().count(), 2);
+    assert_eq!(text.slice(1..3).collect::<Vec<_>>(), [
+        ("b", Style::default()),
+        ("c", bold),
+    ]);
+}
+
+#[test]
+fn styled_text_split_lines_test() {
+    use crate::style::Effect;
+
+    let bold = Style::default().effects(Effect::Bold);
+    let text = StyledText::plain("a\nb").span("c\n", bold);
+    let lines = text.split_lines();
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], StyledText::plain("a"));
+    assert_eq!(lines[1], StyledText::plain("b").span("c", bold));
+    assert_eq!(lines[2], StyledText::new());
+}
@@ -8,7 +8,11 @@ use std::io::{
 #[cfg(any(unix, windows))]
 use std::mem::ManuallyDrop;
 
-use unicode_width::UnicodeWidthChar;
+use std::ops::Range;
+use unicode_width::{
+    UnicodeWidthChar,
+    UnicodeWidthStr,
+};
 
 #[cfg(any(unix, target_os = "wasi"))]
 pub fn get_tty_file() -> File {
@@ -109,6 +113,141 @@ pub fn find_str_width_pos(
     (text.len(), width)
 }
 
+/// Byte position of the char before `pos`
+#[inline]
+pub fn prev_char_pos(
+    text: &str,
+    pos: usize,
+) -> usize {
+    text[..pos]
+        .chars()
+        .next_back()
+        .map_or(pos, |ch| pos - ch.len_utf8())
+}
+
+/// Byte position of the char after `pos`
+#[inline]
+pub fn next_char_pos(
+    text: &str,
+    pos: usize,
+) -> usize {
+    text[pos..]
+        .chars()
+        .next()
+        .map_or(pos, |ch| pos + ch.len_utf8())
+}
+
+/// Byte position of the start of the word before `pos`, whitespaces before it are skipped
+pub fn prev_word_pos(
+    text: &str,
+    pos: usize,
+) -> usize {
+    let head = text[..pos].trim_end_matches(char::is_whitespace);
+    head.trim_end_matches(|ch: char| !ch.is_whitespace()).len()
+}
+
+/// Byte position of the end of the word after `pos`, whitespaces before it are skipped
+pub fn next_word_pos(
+    text: &str,
+    pos: usize,
+) -> usize {
+    let tail = text[pos..].trim_start_matches(char::is_whitespace);
+    let tail = tail.trim_start_matches(|ch: char| !ch.is_whitespace());
+    text.len() - tail.len()
+}
+
+/// Split a line into rows that fit in `width`
+///
+/// Rows are broken at whitespaces and around wide(CJK) characters, words longer than
+/// `width` are broken at any character.
+/// Whitespaces at the wrapping point are not included in any row.
+pub fn wrap_line(
+    text: &str,
+    width: usize,
+) -> Vec<Range<usize>> {
+    let mut rows = Vec::new();
+
+    if width == 0 {
+        rows.push(0..text.len());
+        return rows;
+    }
+
+    let mut start = 0;
+    let mut col = 0;
+    // End of current row and start of next row when wrapped at last break opportunity
+    let mut brk: Option<(usize, usize)> = None;
+    let mut skip_space = false;
+
+    for (i, ch) in text.char_indices() {
+        let ch_width = ch.width().unwrap_or(0);
+        let next = i + ch.len_utf8();
+
+        if ch.is_whitespace() {
+            if skip_space {
+                start = next;
+                continue;
+            }
+
+            if col + ch_width > width {
+                rows.push(start..i);
+                start = next;
+                col = 0;
+                brk = None;
+                skip_space = true;
+            } else {
+                brk = Some((i, next));
+                col += ch_width;
+            }
+
+            continue;
+        }
+
+        skip_space = false;
+
+        if ch_width == 2 && i > start {
+            brk = Some((i, i));
+        }
+
+        if col + ch_width > width && i > start {
+            match brk.take() {
+                Some((end, next_start)) => {
+                    rows.push(start..end);
+                    start = next_start;
+                    col = text[start..i].width();
+                }
+                None => {
+                    rows.push(start..i);
+                    start = i;
+                    col = 0;
+                }
+            }
+
+            // Text moved to new row can still overflow with this character
+            if col + ch_width > width && i > start {
+                rows.push(start..i);
+                start = i;
+                col = 0;
+            }
+        }
+
+        col += ch_width;
+
+        if ch_width == 2 {
+            brk = Some((next, next));
+        }
+    }
+
+    if start < text.len() || rows.is_empty() {
+        rows.push(start..text.len());
+    }
+
+    for row in rows.iter_mut() {
+        row.end = row.start + text[row.clone()].trim_end().len();
+    }
+
+    rows
+}
+
 #[test]
 fn slice_test() {
     assert_eq!(slice_str_with_width("123456", 3), ("123", "456", 0));
@@ -119,3 +258,44 @@ fn slice_test() {
 fn slice_left_test() {
     assert_eq!(slice_str_with_width("가나다라", 3), ("가", "나다라", 1));
 }
+
+#[test]
+fn word_pos_test() {
+    let text = "abc  def gh";
+    assert_eq!(prev_word_pos(text, text.len()), 9);
+    assert_eq!(prev_word_pos(text, 9), 5);
+    assert_eq!(prev_word_pos(text, 4), 0);
+    assert_eq!(next_word_pos(text, 0), 3);
+    assert_eq!(next_word_pos(text, 3), 8);
+    assert_eq!(next_word_pos(text, 8), 11);
+}
+
+#[test]
+fn char_pos_test() {
+    assert_eq!(prev_char_pos("a가", 4), 1);
+    assert_eq!(prev_char_pos("a가", 0), 0);
+    assert_eq!(next_char_pos("a가", 1), 4);
+    assert_eq!(next_char_pos("a가", 4), 4);
+}
+
+#[test]
+fn wrap_line_test() {
+    fn wrap(
+        text: &str,
+        width: usize,
+    ) -> Vec<&str> {
+        wrap_line(text, width)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    assert_eq!(wrap("ab cd", 4), ["ab", "cd"]);
+    assert_eq!(wrap("abcd efg", 4), ["abcd", "efg"]);
+    assert_eq!(wrap("abcdef", 4), ["abcd", "ef"]);
+    assert_eq!(wrap("ab  ", 2), ["ab"]);
+    assert_eq!(wrap("", 2), [""]);
+    assert_eq!(wrap("가나다", 4), ["가나", "다"]);
+    assert_eq!(wrap("ab가나", 3), ["ab", "가", "나"]);
+    assert_eq!(wrap("a bcdefgh", 4), ["a", "bcde", "fgh"]);
+}
@@ -6,11 +6,15 @@ use crate::{
         OrElse,
         OrElseFirst,
     },
+    focus::FocusDirection,
+    keymap::KeyMap,
     orientation::Orientation,
     printer::Printer,
+    rect::Rect,
     vec2::Vec2,
     view_wrappers::{
         ConsumeEvent,
+        KeyMapView,
         ScrollView,
     },
 };
@@ -34,6 +38,48 @@ pub trait View<S, E> {
         event: E,
     ) -> Option<Self::Message>;
 
+    /// Move focus one step in `direction` inside this view
+    ///
+    /// Returns `false` when focus moved out of this view or it can't take focus.
+    /// Views that take focus by itself give up focus when it's already focused.
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        false
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {}
+
+    /// Whether a key sequence inside this view waits for more keys
+    ///
+    /// [`KeyMapView`] doesn't look up keys while the view it wraps has pending keys so outer
+    /// scopes don't take over chords of inner ones. Containers ask the child which gets keys.
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        false
+    }
+
+    /// Area of this view which has focus, `None` when it doesn't have focus
+    ///
+    /// [`ScrollView`] scrolls it into view when `Tab` moves focus. It is whole view by default,
+    /// containers narrow it down to the focused child.
+    #[inline]
+    fn focus_rect(&self) -> Option<Rect> {
+        if self.has_focus() {
+            Some(Rect::new((0, 0), self.desired_size()))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn scrollable(
         self,
@@ -57,6 +103,17 @@ pub trait View<S, E> {
         ConsumeEvent::new(self, msg)
     }
 
+    #[inline]
+    fn keymap(
+        self,
+        keymap: KeyMap<Self::Message>,
+    ) -> KeyMapView<Self, Self::Message>
+    where
+        Self: Sized,
+    {
+        KeyMapView::new(self, keymap)
+    }
+
     #[inline]
     fn map<U, F>(
         self,
@@ -150,4 +207,32 @@ impl<S, E, M> View<S, E> for Box<dyn View<S, E, Message = M>> {
     ) -> Option<M> {
         (**self).on_event(state, event)
     }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        (**self).take_focus(direction)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        (**self).has_focus()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        (**self).release_focus();
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        (**self).has_pending_keys()
+    }
+
+    #[inline]
+    fn focus_rect(&self) -> Option<Rect> {
+        (**self).focus_rect()
+    }
 }
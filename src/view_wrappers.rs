@@ -1,11 +1,19 @@
 use crate::{
     event::{
         EventLike,
+        KeyEvent,
         KeyEventLike,
+        KeyModifier,
         MouseEventLike,
     },
+    focus::FocusDirection,
+    keymap::{
+        KeyLookup,
+        KeyMap,
+    },
     orientation::Orientation,
     printer::Printer,
+    rect::Rect,
     state::RedrawState,
     vec2::Vec2,
     view::View,
@@ -44,11 +52,207 @@ where
     }
 }
 
-pub struct ScrollView<T> {
-    inner:       SizeCacher<T>,
+/// Converts key sequences into messages with [`KeyMap`]
+///
+/// Keys are given to inner view first, bindings are only looked up when it doesn't return
+/// a message and has no [pending keys](View::has_pending_keys). So when `KeyMapView`s are
+/// nested the innermost scope wins and unbound keys fall through to parent scopes. While a
+/// chord is pending the keys go straight to the keymap.
+pub struct KeyMapView<T, M> {
+    inner:   T,
+    keymap:  KeyMap<M>,
+    pending: Vec<KeyEvent>,
+}
+
+impl<T, M> KeyMapView<T, M> {
+    pub fn new(
+        inner: T,
+        keymap: KeyMap<M>,
+    ) -> Self {
+        Self {
+            inner,
+            keymap,
+            pending: Vec::with_capacity(4),
+        }
+    }
+
+    #[inline]
+    pub fn keymap(&self) -> &KeyMap<M> {
+        &self.keymap
+    }
+
+    #[inline]
+    pub fn keymap_mut(&mut self) -> &mut KeyMap<M> {
+        &mut self.keymap
+    }
+
+    /// Keys of chord which is not finished yet
+    #[inline]
+    pub fn pending(&self) -> &[KeyEvent] {
+        &self.pending
+    }
+
+    fn feed(
+        &mut self,
+        key: KeyEvent,
+    ) -> Option<M>
+    where
+        M: Clone,
+    {
+        self.pending.push(key);
+
+        match self.keymap.lookup(&self.pending) {
+            KeyLookup::Match(msg) => {
+                let msg = msg.clone();
+                self.pending.clear();
+                Some(msg)
+            }
+            KeyLookup::Prefix => None,
+            KeyLookup::None => {
+                self.pending.clear();
+                None
+            }
+        }
+    }
+}
+
+impl<S, E, T, M> View<S, E> for KeyMapView<T, M>
+where
+    E: EventLike,
+    M: Clone,
+    T: View<S, E, Message = M>,
+{
+    type Message = M;
+
+    #[inline]
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        self.inner.render(printer);
+    }
+
+    #[inline]
+    fn desired_size(&self) -> Vec2 {
+        self.inner.desired_size()
+    }
+
+    #[inline]
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.inner.layout(size);
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        self.inner.take_focus(direction)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.inner.has_focus()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.inner.release_focus();
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        !self.pending.is_empty() || self.inner.has_pending_keys()
+    }
+
+    #[inline]
+    fn focus_rect(&self) -> Option<Rect> {
+        self.inner.focus_rect()
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        event: E,
+    ) -> Option<M> {
+        let key = match event.try_key() {
+            Some(ke) => ke.key(),
+            None => return self.inner.on_event(state, event),
+        };
+
+        if !self.pending.is_empty() {
+            if let Some(msg) = self.feed(key) {
+                return Some(msg);
+            }
+
+            if !self.pending.is_empty() {
+                return None;
+            }
+        }
+
+        if let Some(msg) = self.inner.on_event(state, event) {
+            return Some(msg);
+        }
+
+        // Inner scope waits for rest of its chord
+        if self.inner.has_pending_keys() {
+            return None;
+        }
+
+        self.feed(key)
+    }
+}
+
+/// Length of `v` along `orientation`
+#[inline]
+fn axis(
+    v: Vec2,
+    orientation: Orientation,
+) -> u16 {
+    match orientation {
+        Orientation::Horizontal => v.x,
+        Orientation::Vertical => v.y,
+    }
+}
+
+#[inline]
+fn with_axis(
+    v: Vec2,
     orientation: Orientation,
-    scroll:      u16,
-    clicked:     bool,
+    len: u16,
+) -> Vec2 {
+    match orientation {
+        Orientation::Horizontal => Vec2::new(len, v.y),
+        Orientation::Vertical => Vec2::new(v.x, len),
+    }
+}
+
+/// Scrolls content which is larger than the view
+///
+/// Scrollbars are drawn on the right and at the bottom, with a corner cell when both axes
+/// scroll. Mouse wheel scrolls vertically, `Shift` + wheel horizontally.
+///
+/// Focused content gets keys first, arrows and paging keys it doesn't use scroll the view.
+/// A child focused by `Tab` is scrolled into view.
+pub struct ScrollView<T> {
+    inner:      SizeCacher<T>,
+    horizontal: bool,
+    vertical:   bool,
+    /// Hide scrollbar of an axis when content fits
+    auto_hide:  bool,
+    /// Scrollbars shown after layout, `x` for vertical one and `y` for horizontal one
+    bars:       Vec2,
+    /// Content cell shown at top-left corner
+    offset:     Vec2,
+    /// Size of visible part of content
+    viewport:   Vec2,
+    /// Scrollbar which is being dragged and where its thumb was grabbed
+    dragging:   Option<(Orientation, u16)>,
+    /// Focused by itself because content doesn't take focus
+    focused:    bool,
 }
 
 impl<T> ScrollView<T> {
@@ -56,98 +260,279 @@ impl<T> ScrollView<T> {
         inner: T,
         orientation: Orientation,
     ) -> Self {
+        let mut view = Self::both(inner);
+        view.horizontal = orientation == Orientation::Horizontal;
+        view.vertical = orientation == Orientation::Vertical;
+        view.bars = view.enabled_bars();
+        view
+    }
+
+    /// Scroll on both axes
+    pub fn both(inner: T) -> Self {
         Self {
-            inner: SizeCacher::new(inner),
-            orientation,
-            scroll: 0,
-            clicked: false,
+            inner:      SizeCacher::new(inner),
+            horizontal: true,
+            vertical:   true,
+            auto_hide:  false,
+            bars:       Vec2::new(1, 1),
+            offset:     Vec2::new(0, 0),
+            viewport:   Vec2::new(0, 0),
+            dragging:   None,
+            focused:    false,
         }
     }
 
+    /// Don't show scrollbar of an axis when content fits in the view
     #[inline]
-    fn down(&mut self) -> bool {
-        self.scroll = self.scroll.saturating_sub(1);
-        self.scroll != 0
+    pub fn auto_hide(
+        mut self,
+        auto_hide: bool,
+    ) -> Self {
+        self.set_auto_hide(auto_hide);
+        self
     }
 
     #[inline]
-    fn up(&mut self) -> bool {
-        let can_up = self.scroll + 1
-            < match self.orientation {
-                Orientation::Vertical => self.inner.prev_size.y,
-                Orientation::Horizontal => self.inner.prev_size.x,
+    pub fn set_auto_hide(
+        &mut self,
+        auto_hide: bool,
+    ) {
+        self.auto_hide = auto_hide;
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &T {
+        self.inner.inner()
+    }
+
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut T {
+        self.inner.inner_mut()
+    }
+
+    /// Content cell shown at top-left corner
+    ///
+    /// Offset given to [`scroll_to`](Self::scroll_to) is returned as is until next layout
+    /// clamps it to content size.
+    #[inline]
+    pub fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    /// Show content from `offset`, it is clamped to content size on next layout
+    pub fn scroll_to(
+        &mut self,
+        offset: impl Into<Vec2>,
+    ) {
+        let offset = offset.into();
+
+        self.offset = Vec2::new(
+            if self.horizontal { offset.x } else { 0 },
+            if self.vertical { offset.y } else { 0 },
+        );
+    }
+
+    /// Scroll as little as possible so `rect` of content is visible
+    ///
+    /// Start of `rect` is preferred when it is larger than the view.
+    pub fn scroll_into_view(
+        &mut self,
+        rect: Rect,
+    ) {
+        let viewport = self.viewport;
+        let mut offset = self.offset;
+
+        for orientation in [Orientation::Horizontal, Orientation::Vertical] {
+            let start = axis(rect.start(), orientation);
+            let len = axis(rect.size(), orientation);
+            let view_len = axis(viewport, orientation);
+            let current = axis(offset, orientation);
+
+            let end = start.saturating_add(len);
+            let new = if start < current || len > view_len {
+                start
+            } else if end > current.saturating_add(view_len) {
+                end - view_len
+            } else {
+                current
             };
 
-        if can_up {
-            self.scroll += 1;
-            true
-        } else {
-            false
+            offset = with_axis(offset, orientation, new);
         }
+
+        self.scroll_to(offset);
     }
 
     #[inline]
-    fn additional_size(&self) -> Vec2 {
-        match self.orientation {
-            Orientation::Horizontal => Vec2::new(0, 1),
-            Orientation::Vertical => Vec2::new(1, 0),
+    fn scrolls(
+        &self,
+        orientation: Orientation,
+    ) -> bool {
+        match orientation {
+            Orientation::Horizontal => self.horizontal,
+            Orientation::Vertical => self.vertical,
         }
     }
 
+    /// Scrollbars of scrolled axes
+    #[inline]
+    fn enabled_bars(&self) -> Vec2 {
+        Vec2::new(self.vertical as u16, self.horizontal as u16)
+    }
+
+    /// Axis which `PageUp`, `PageDown`, `Home` and `End` move
     #[inline]
-    fn scroll_block_text(&self) -> &'static str {
-        if self.clicked {
-            "█"
+    fn main_axis(&self) -> Orientation {
+        if self.vertical {
+            Orientation::Vertical
         } else {
-            "░"
+            Orientation::Horizontal
         }
     }
 
     #[inline]
-    fn event_mouse_down(
+    fn track_len(
+        &self,
+        orientation: Orientation,
+    ) -> u16 {
+        axis(self.viewport, orientation)
+    }
+
+    /// Content cells which don't fit in the view
+    #[inline]
+    fn left(
+        &self,
+        orientation: Orientation,
+        content: Vec2,
+    ) -> u16 {
+        axis(content, orientation).saturating_sub(self.track_len(orientation))
+    }
+
+    #[inline]
+    fn clamped_offset(
+        &self,
+        content: Vec2,
+    ) -> Vec2 {
+        Vec2::new(
+            self.offset
+                .x
+                .min(self.left(Orientation::Horizontal, content)),
+            self.offset.y.min(self.left(Orientation::Vertical, content)),
+        )
+    }
+
+    /// Position and length of thumb, proportional to visible part of content
+    fn thumb(
+        &self,
+        orientation: Orientation,
+        content: Vec2,
+    ) -> (u16, u16) {
+        let track = self.track_len(orientation) as u32;
+        let content_len = (axis(content, orientation) as u32).max(track).max(1);
+        let len = (track * track / content_len).max(1).min(track);
+
+        let left = self.left(orientation, content) as u32;
+        let offset = axis(self.offset, orientation) as u32;
+        let pos = match left {
+            0 => 0,
+            left => offset.min(left) * (track - len) / left,
+        };
+
+        (pos as u16, len as u16)
+    }
+
+    /// Move content, returns `false` when nothing moved
+    fn set_offset(
+        &mut self,
+        orientation: Orientation,
+        offset: u16,
+        content: Vec2,
+    ) -> bool {
+        let offset = offset.min(self.left(orientation, content));
+        let moved = offset != axis(self.offset, orientation);
+
+        self.offset = with_axis(self.offset, orientation, offset);
+        moved
+    }
+
+    #[inline]
+    fn step(
         &mut self,
-        pos: Vec2,
-        state: &mut impl RedrawState,
+        orientation: Orientation,
+        forward: bool,
+        len: u16,
+        content: Vec2,
     ) -> bool {
-        match self.orientation {
-            Orientation::Vertical => {
-                if pos.x == self.inner.prev_size().x {
-                    self.clicked = true;
-                    self.scroll = pos.y;
-                    state.set_need_redraw(true);
-                    true
-                } else {
-                    false
-                }
-            }
-            Orientation::Horizontal => {
-                if pos.y == self.inner.prev_size().y {
-                    self.clicked = true;
-                    self.scroll = pos.x;
-                    state.set_need_redraw(true);
-                    true
-                } else {
-                    false
-                }
-            }
+        let offset = axis(self.clamped_offset(content), orientation);
+        let offset = if forward {
+            offset.saturating_add(len)
+        } else {
+            offset.saturating_sub(len)
+        };
+        self.set_offset(orientation, offset, content)
+    }
+
+    /// Move content so thumb starts at `pos`
+    fn drag_thumb(
+        &mut self,
+        orientation: Orientation,
+        pos: u16,
+        content: Vec2,
+    ) -> bool {
+        let (_, len) = self.thumb(orientation, content);
+        let room = self.track_len(orientation).saturating_sub(len) as u32;
+        let left = self.left(orientation, content) as u32;
+
+        let offset = match room {
+            0 => 0,
+            room => (pos as u32).min(room) * left / room,
+        };
+        self.set_offset(orientation, offset as u16, content)
+    }
+
+    /// Start drag on the thumb or page towards the click on the track
+    fn click_track(
+        &mut self,
+        orientation: Orientation,
+        pos: u16,
+        content: Vec2,
+    ) {
+        let (start, len) = self.thumb(orientation, content);
+        let page = self.track_len(orientation);
+
+        if pos < start {
+            self.step(orientation, false, page, content);
+        } else if pos >= start + len {
+            self.step(orientation, true, page, content);
+        } else {
+            self.dragging = Some((orientation, pos - start));
         }
     }
 
     #[inline]
-    fn event_drag(
-        &mut self,
+    fn scroll_block_text(
+        &self,
+        orientation: Orientation,
+    ) -> &'static str {
+        match self.dragging {
+            Some((dragging, _)) if dragging == orientation => "█",
+            _ => "░",
+        }
+    }
+
+    /// Scrollbar under `pos`
+    fn scrollbar_at(
+        &self,
         pos: Vec2,
-        state: &mut impl RedrawState,
-    ) -> bool {
-        if self.clicked {
-            self.scroll = match self.orientation {
-                Orientation::Vertical => pos.y,
-                Orientation::Horizontal => pos.x,
-            };
-            state.set_need_redraw(true);
-            true
+    ) -> Option<Orientation> {
+        let viewport = self.viewport;
+
+        if self.bars.x > 0 && pos.x == viewport.x && pos.y < viewport.y {
+            Some(Orientation::Vertical)
+        } else if self.bars.y > 0 && pos.y == viewport.y && pos.x < viewport.x {
+            Some(Orientation::Horizontal)
         } else {
-            false
+            None
         }
     }
 }
@@ -162,140 +547,256 @@ where
         &self,
         printer: &mut Printer,
     ) {
-        let inner_size = self.inner.desired_size();
-
-        let pos = match self.orientation {
-            Orientation::Horizontal => {
-                match inner_size.x.checked_sub(printer.bound().w()) {
-                    Some(left) => left * self.scroll / (printer.bound().w() - 1),
-                    None => {
-                        // Nothing to scroll
-                        self.inner.render(printer);
-                        return;
-                    }
-                }
+        let content = self.inner.desired_size();
+        let viewport = self.viewport;
+        let offset = self.clamped_offset(content);
+
+        printer.with_bound(Rect::new(printer.bound().start(), viewport), |printer| {
+            printer.sliced(offset, |printer| {
+                self.inner.render(printer);
+            });
+        });
+
+        if self.bars.y > 0 {
+            let (pos, len) = self.thumb(Orientation::Horizontal, content);
+            let block = self.scroll_block_text(Orientation::Horizontal);
+
+            printer.print_horizontal_line_at((0, viewport.y), viewport.x as usize);
+            printer.print((pos, viewport.y), &block.repeat(len as usize));
+        }
+
+        if self.bars.x > 0 {
+            let (pos, len) = self.thumb(Orientation::Vertical, content);
+            let block = self.scroll_block_text(Orientation::Vertical);
+
+            printer.print_vertical_line_at((viewport.x, 0), viewport.y as usize);
+            for y in pos..pos + len {
+                printer.print((viewport.x, y), block);
             }
-            Orientation::Vertical => {
-                match inner_size.y.checked_sub(printer.bound().h()) {
-                    Some(left) => left * self.scroll / (printer.bound().h() - 1),
-                    None => {
-                        // Nothing to scroll
-                        self.inner.render(printer);
-                        return;
-                    }
+        }
+
+        if self.bars.x > 0 && self.bars.y > 0 {
+            printer.print(viewport, "┘");
+        }
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.bars = if self.auto_hide {
+            Vec2::new(0, 0)
+        } else {
+            self.enabled_bars()
+        };
+
+        self.viewport = size.saturating_sub(self.bars);
+        self.inner.layout(self.viewport);
+
+        if self.auto_hide {
+            // Showing one scrollbar can make content overflow on the other axis
+            for _ in 0..2 {
+                let desired = self.inner.desired_size();
+                let bars = Vec2::new(
+                    (self.vertical && desired.y > self.viewport.y) as u16,
+                    (self.horizontal && desired.x > self.viewport.x) as u16,
+                );
+
+                if bars == self.bars {
+                    break;
                 }
+
+                self.bars = bars;
+                self.viewport = size.saturating_sub(self.bars);
+                self.inner.layout(self.viewport);
             }
-        };
+        }
 
-        printer.with_bound(
-            printer.bound().sub_size(self.additional_size()),
-            |printer| {
-                let pos = match self.orientation {
-                    Orientation::Horizontal => (pos, 0),
-                    Orientation::Vertical => (0, pos),
-                };
-                printer.sliced(pos, |printer| {
-                    self.inner.render(printer);
-                });
+        // Scrolled axes get whole content, desired size may depend on the other axis
+        let desired = self.inner.desired_size();
+        let content = Vec2::new(
+            if self.horizontal {
+                desired.x.max(self.viewport.x)
+            } else {
+                self.viewport.x
+            },
+            if self.vertical {
+                desired.y.max(self.viewport.y)
+            } else {
+                self.viewport.y
             },
         );
 
-        match self.orientation {
-            Orientation::Horizontal => {
-                let pos = printer.bound().h() - 1;
-                printer.print_horizontal_line(pos);
-                printer.print((self.scroll, pos), self.scroll_block_text());
-            }
-            Orientation::Vertical => {
-                let pos = printer.bound().w() - 1;
-                printer.print_vertical_line(pos);
-                printer.print((pos, self.scroll), self.scroll_block_text());
-            }
+        if content != self.viewport {
+            self.inner.layout(content);
         }
+
+        self.offset = self.clamped_offset(content);
     }
 
-    fn layout(
+    fn desired_size(&self) -> Vec2 {
+        if self.auto_hide {
+            self.inner.desired_size()
+        } else {
+            self.inner.desired_size() + self.enabled_bars()
+        }
+    }
+
+    /// Focus goes to content, scroll view takes it by itself when content can't take focus so
+    /// it can be scrolled with keys
+    fn take_focus(
         &mut self,
-        mut size: Vec2,
-    ) {
-        size = size.saturating_sub(self.additional_size());
-        self.inner.layout(size);
+        direction: FocusDirection,
+    ) -> bool {
+        let had_focus = self.inner.has_focus();
+
+        if self.inner.take_focus(direction) {
+            return true;
+        } else if had_focus {
+            return false;
+        }
+
+        self.focused = !self.focused;
+        self.focused
     }
 
-    fn desired_size(&self) -> Vec2 {
-        self.inner.desired_size() + self.additional_size()
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused || self.inner.has_focus()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+        self.inner.release_focus();
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.inner.has_pending_keys()
     }
 
     fn on_event(
         &mut self,
         state: &mut S,
-        event: E,
+        mut event: E,
     ) -> Option<Self::Message> {
-        // TODO: check focus
+        let content = self.inner.desired_size();
+
+        if let Some(me) = event.try_mouse_mut() {
+            let pos = me.pos();
 
-        if let Some(me) = event.try_mouse() {
-            if me.try_left_up().is_some() {
-                self.clicked = false;
+            if me.try_left_up().is_some() && self.dragging.is_some() {
+                self.dragging = None;
+                state.set_need_redraw(true);
+                return None;
             } else if let Some(pos) = me.try_left_down() {
-                if self.event_mouse_down(pos, state) {
+                if let Some(orientation) = self.scrollbar_at(pos) {
+                    self.click_track(orientation, axis(pos, orientation), content);
+                    state.set_need_redraw(true);
                     return None;
                 }
             } else if let Some(pos) = me.try_drag() {
-                if self.event_drag(pos, state) {
+                if let Some((orientation, grab)) = self.dragging {
+                    let start = axis(pos, orientation).saturating_sub(grab);
+                    if self.drag_thumb(orientation, start, content) {
+                        state.set_need_redraw(true);
+                    }
                     return None;
                 }
-            } else if let Some(pos) = me.try_scroll_up() {
-                let inner_size = self.inner.prev_size();
-                match self.orientation {
-                    Orientation::Vertical if self.scroll < inner_size.x => {
-                        if self.up() {
-                            state.set_need_redraw(true);
-                        }
-                        return None;
-                    }
-                    Orientation::Horizontal if pos.y == inner_size.y => {
-                        if self.up() {
-                            state.set_need_redraw(true);
-                        }
-                        return None;
+            } else if me.try_scroll_up().is_some() || me.try_scroll_down().is_some() {
+                let shift = me.modifiers().contains(KeyModifier::Shift);
+                let orientation = if shift || !self.vertical {
+                    Orientation::Horizontal
+                } else {
+                    Orientation::Vertical
+                };
+
+                // Plain wheel scrolls horizontal only view over its scrollbar
+                if self.scrolls(orientation)
+                    && (shift
+                        || self.vertical
+                        || self.scrollbar_at(pos) == Some(Orientation::Horizontal))
+                {
+                    if self.step(orientation, me.try_scroll_down().is_some(), 1, content) {
+                        state.set_need_redraw(true);
                     }
-                    _ => {}
+                    return None;
                 }
-            } else if let Some(pos) = me.try_scroll_down() {
-                let inner_size = self.inner.prev_size();
-                match self.orientation {
-                    Orientation::Vertical if self.scroll < inner_size.x => {
-                        if self.down() {
-                            state.set_need_redraw(true);
-                        }
-                        return None;
-                    }
-                    Orientation::Horizontal if pos.y == inner_size.y => {
-                        if self.down() {
-                            state.set_need_redraw(true);
-                        }
-                        return None;
-                    }
-                    _ => {}
+            }
+
+            let viewport = self.viewport;
+            let offset = self.clamped_offset(content);
+
+            if !me.filter_map_pos(|pos| {
+                if pos.x < viewport.x && pos.y < viewport.y {
+                    Some(pos + offset)
+                } else {
+                    None
                 }
+            }) {
+                return None;
             }
         } else if let Some(ke) = event.try_key() {
-            if ke.try_up() && self.orientation == Orientation::Vertical
-                || ke.try_left() && self.orientation == Orientation::Horizontal
-            {
-                if self.down() {
-                    state.set_need_redraw(true);
+            let main = self.main_axis();
+            let page = self.track_len(main);
+            let tab = ke.try_tab() || ke.try_back_tab();
+
+            let step = if ke.try_up() && self.vertical {
+                Some((Orientation::Vertical, false, 1))
+            } else if ke.try_down() && self.vertical {
+                Some((Orientation::Vertical, true, 1))
+            } else if ke.try_left() && self.horizontal {
+                Some((Orientation::Horizontal, false, 1))
+            } else if ke.try_right() && self.horizontal {
+                Some((Orientation::Horizontal, true, 1))
+            } else if ke.try_page_up() {
+                Some((main, false, page))
+            } else if ke.try_page_down() {
+                Some((main, true, page))
+            } else if ke.try_home() {
+                Some((main, false, u16::MAX))
+            } else if ke.try_end() {
+                Some((main, true, u16::MAX))
+            } else {
+                None
+            };
+
+            // Focused content gets keys first, keys it doesn't use scroll the view
+            if self.inner.has_focus() || tab {
+                let redraw = state.is_need_redraw();
+                state.set_need_redraw(false);
+
+                let msg = self.inner.on_event(state, event);
+                let used = msg.is_some() || state.is_need_redraw();
+                state.set_need_redraw(redraw || used);
+
+                if tab {
+                    if let Some(rect) = self.inner.focus_rect() {
+                        self.scroll_into_view(rect);
+                    }
+                }
+
+                if used || self.inner.has_pending_keys() {
+                    return msg;
+                }
+
+                if let Some((orientation, forward, len)) = step {
+                    if self.step(orientation, forward, len, content) {
+                        state.set_need_redraw(true);
+                    }
                 }
                 return None;
-            } else if ke.try_down() && self.orientation == Orientation::Vertical
-                || ke.try_right() && self.orientation == Orientation::Horizontal
-            {
-                if self.up() {
+            }
+
+            if let Some((orientation, forward, len)) = step {
+                if self.step(orientation, forward, len, content) {
                     state.set_need_redraw(true);
                 }
                 return None;
             }
         }
+
         self.inner.on_event(state, event)
     }
 }
@@ -318,6 +819,16 @@ impl<T> SizeCacher<T> {
     pub fn prev_size(&self) -> Vec2 {
         self.prev_size
     }
+
+    #[inline]
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
 }
 
 impl<S, E, T> View<S, E> for SizeCacher<T>
@@ -356,4 +867,32 @@ where
     ) -> Option<Self::Message> {
         self.inner.on_event(state, event)
     }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        self.inner.take_focus(direction)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.inner.has_focus()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.inner.release_focus();
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.inner.has_pending_keys()
+    }
+
+    #[inline]
+    fn focus_rect(&self) -> Option<Rect> {
+        self.inner.focus_rect()
+    }
 }
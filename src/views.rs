@@ -1,30 +1,71 @@
 mod button_view;
+mod context_menu_view;
 mod dialog_view;
 mod edit_view;
 mod fps_view;
+mod grid_view;
 mod layered_view;
 mod linear_view;
+mod list_view;
+mod menu_bar_view;
 mod paragraph_view;
 mod select_view;
+mod split_view;
+mod tab_view;
+mod table_view;
+mod text_area_view;
 mod text_view;
+mod tree_view;
 
 pub use self::{
     button_view::{
         ButtonDecoration,
         ButtonView,
     },
+    context_menu_view::ContextMenuView,
     dialog_view::DialogView,
     edit_view::{
         EditView,
         EditViewMessage,
     },
     fps_view::FpsView,
-    layered_view::LayeredView,
+    grid_view::GridView,
+    layered_view::{
+        LayeredView,
+        Placement,
+    },
     linear_view::LinearView,
+    list_view::{
+        ListSource,
+        ListView,
+        ListViewMessage,
+    },
+    menu_bar_view::{
+        Menu,
+        MenuBarView,
+        MenuItem,
+    },
     paragraph_view::ParagraphView,
     select_view::{
         SelectView,
         SelectViewMessage,
     },
+    split_view::SplitView,
+    tab_view::TabView,
+    table_view::{
+        SortOrder,
+        TableColumn,
+        TableRow,
+        TableView,
+        TableViewMessage,
+    },
+    text_area_view::{
+        TextAreaView,
+        TextAreaViewMessage,
+    },
     text_view::TextView,
+    tree_view::{
+        TreeView,
+        TreeViewMessage,
+    },
 };
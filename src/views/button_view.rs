@@ -4,38 +4,56 @@ use crate::{
         KeyEventLike,
         MouseEventLike,
     },
+    focus::FocusDirection,
     printer::Printer,
     style::Style,
+    styled_text::StyledText,
     vec2::Vec2,
     view::View,
 };
 use std::marker::PhantomData;
-use unicode_width::UnicodeWidthStr;
 
 pub struct ButtonView<S, E> {
-    text:       String,
+    text:       StyledText,
     text_width: u16,
+    focused:    bool,
     _marker:    PhantomData<(S, E)>,
 }
 
 impl<S, E> ButtonView<S, E> {
+    #[inline]
     pub fn new(
-        mut text: String,
+        text: impl Into<String>,
+        decoration: ButtonDecoration,
+    ) -> Self {
+        Self::styled(StyledText::plain(text), decoration)
+    }
+
+    /// Button with styled label, decoration is drawn with button style
+    pub fn styled(
+        text: impl Into<StyledText>,
         decoration: ButtonDecoration,
     ) -> Self {
-        decoration.decoration(&mut text);
+        let text = decoration.decoration(text.into());
 
         let text_width = text.width() as u16;
 
         Self {
             text,
             text_width,
+            focused: false,
             _marker: PhantomData,
         }
     }
 
+    /// Label with decoration
     #[inline]
     pub fn text(&self) -> &str {
+        self.text.source()
+    }
+
+    #[inline]
+    pub fn styled_text(&self) -> &StyledText {
         &self.text
     }
 
@@ -45,9 +63,10 @@ impl<S, E> ButtonView<S, E> {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum ButtonDecoration {
     NoDecoration,
+    #[default]
     Angle,
 }
 
@@ -55,24 +74,20 @@ impl ButtonDecoration {
     #[inline]
     fn decoration(
         &self,
-        text: &mut String,
-    ) {
+        text: StyledText,
+    ) -> StyledText {
         match self {
-            ButtonDecoration::NoDecoration => {}
+            ButtonDecoration::NoDecoration => text,
             ButtonDecoration::Angle => {
-                text.insert(0, '<');
-                text.push('>');
+                let mut decorated = StyledText::plain("<");
+                decorated.append_text(&text);
+                decorated.append(">");
+                decorated
             }
         }
     }
 }
 
-impl Default for ButtonDecoration {
-    fn default() -> Self {
-        Self::Angle
-    }
-}
-
 impl<S, E: EventLike> View<S, E> for ButtonView<S, E> {
     type Message = ();
 
@@ -93,11 +108,36 @@ impl<S, E: EventLike> View<S, E> for ButtonView<S, E> {
         &self,
         printer: &mut Printer,
     ) {
-        printer.with_style(Style::view(), |printer| {
-            printer.print((0, 0), &self.text);
+        let style = if self.focused {
+            Style::highlight()
+        } else {
+            Style::view()
+        };
+
+        printer.with_style(style, |printer| {
+            printer.print_styled_text((0, 0), &self.text);
         });
     }
 
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
     #[inline]
     fn on_event(
         &mut self,
@@ -0,0 +1,180 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyEventLike,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
+    printer::Printer,
+    state::RedrawState,
+    vec2::Vec2,
+    view::View,
+    views::Menu,
+};
+use std::marker::PhantomData;
+
+/// Popup list of [`Menu`] items
+///
+/// Submenus replace the list when they are opened, `Left` goes back to the parent menu.
+/// Show it with [`LayeredView::add_popup`](crate::views::LayeredView::add_popup) so it closes on
+/// selection, `Esc` or a click outside.
+pub struct ContextMenuView<S, E, M> {
+    menu:      Menu<M>,
+    /// Indices of opened submenus
+    path:      Vec<usize>,
+    highlight: usize,
+    focused:   bool,
+    _marker:   PhantomData<(S, E)>,
+}
+
+impl<S, E, M> ContextMenuView<S, E, M> {
+    pub fn new(menu: Menu<M>) -> Self {
+        Self {
+            highlight: menu.first_selectable(),
+            menu,
+            path: Vec::with_capacity(3),
+            focused: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Menu currently shown
+    fn current(&self) -> &Menu<M> {
+        let mut menu = &self.menu;
+
+        for &i in self.path.iter() {
+            menu = match menu.items()[i].menu() {
+                Some(sub) => sub,
+                None => break,
+            };
+        }
+
+        menu
+    }
+
+    fn open_submenu(&mut self) -> bool {
+        let sub = match self.current().items().get(self.highlight) {
+            Some(item) if item.is_enabled() => item.menu(),
+            _ => None,
+        };
+
+        match sub.map(Menu::first_selectable) {
+            Some(first) => {
+                self.path.push(self.highlight);
+                self.highlight = first;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn activate(&mut self) -> Option<M>
+    where
+        M: Clone,
+    {
+        if self.open_submenu() {
+            return None;
+        }
+
+        let item = self.current().items().get(self.highlight)?;
+
+        if item.is_enabled() {
+            item.message().cloned()
+        } else {
+            None
+        }
+    }
+}
+
+impl<S, E, M> View<S, E> for ContextMenuView<S, E, M>
+where
+    S: RedrawState,
+    E: EventLike,
+    M: Clone,
+{
+    type Message = M;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        self.current().render(printer, Some(self.highlight));
+    }
+
+    fn layout(
+        &mut self,
+        _size: Vec2,
+    ) {
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        self.current().size()
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        e: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = e.try_mouse() {
+            let pos = me.try_left_down()?;
+            let index = (pos.y as usize).checked_sub(1)?;
+
+            if !self.current().items().get(index)?.selectable() {
+                return None;
+            }
+
+            self.highlight = index;
+            state.set_need_redraw(true);
+            return self.activate();
+        }
+
+        let ke = e.try_key()?;
+
+        if ke.try_enter() {
+            state.set_need_redraw(true);
+            self.activate()
+        } else if ke.try_right() {
+            if self.open_submenu() {
+                state.set_need_redraw(true);
+            }
+            None
+        } else if ke.try_left() {
+            if let Some(parent) = self.path.pop() {
+                self.highlight = parent;
+                state.set_need_redraw(true);
+            }
+            None
+        } else if ke.try_up() || ke.try_down() {
+            if let Some(next) = self
+                .current()
+                .next_selectable(self.highlight, ke.try_down())
+            {
+                self.highlight = next;
+                state.set_need_redraw(true);
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
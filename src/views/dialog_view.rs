@@ -5,9 +5,16 @@ use crate::{
         KeyEventLike,
         MouseEventLike,
     },
+    focus::{
+        cycle_focus,
+        walk_focus,
+        FocusDirection,
+    },
     printer::Printer,
+    rect::Rect,
     state::RedrawState,
     style::Style,
+    styled_text::StyledText,
     vec2::Vec2,
     view::View,
     view_wrappers::SizeCacher,
@@ -28,6 +35,7 @@ type DialogButton<S, E, M> =
 
 pub struct DialogView<S, E, M, C> {
     title:   String,
+    error:   Option<String>,
     content: SizeCacher<C>,
     buttons: Vec<DialogButton<S, E, M>>,
     focus:   DialogFocus,
@@ -42,6 +50,7 @@ where
     pub fn new(content: C) -> Self {
         Self {
             title:   String::new(),
+            error:   None,
             content: SizeCacher::new(content),
             buttons: Vec::with_capacity(10),
             focus:   DialogFocus::Content,
@@ -65,6 +74,23 @@ where
         self
     }
 
+    /// Show error message on the bottom outline
+    ///
+    /// Validation errors of [`EditView`](crate::views::EditView) can be passed with
+    /// `dialog.set_error(dialog.content().error().map(String::from))`.
+    #[inline]
+    pub fn set_error(
+        &mut self,
+        error: Option<String>,
+    ) {
+        self.error = error;
+    }
+
+    #[inline]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     #[inline]
     pub fn button(
         mut self,
@@ -75,25 +101,129 @@ where
         self
     }
 
+    #[inline]
     pub fn add_button(
         &mut self,
         label: impl Into<String>,
+        f: impl FnMut(&mut S) -> M + 'static,
+    ) {
+        self.add_styled_button(StyledText::plain(label), f);
+    }
+
+    #[inline]
+    pub fn styled_button(
+        mut self,
+        label: impl Into<StyledText>,
+        f: impl FnMut(&mut S) -> M + 'static,
+    ) -> Self {
+        self.add_styled_button(label, f);
+        self
+    }
+
+    pub fn add_styled_button(
+        &mut self,
+        label: impl Into<StyledText>,
         mut f: impl FnMut(&mut S) -> M + 'static,
     ) {
         self.buttons.push(
-            ButtonView::new(label.into(), ButtonDecoration::Angle)
+            ButtonView::styled(label, ButtonDecoration::Angle)
                 .map(Box::new(move |_, state, _| f(state))),
         );
     }
 
     #[inline]
-    fn tab(&mut self) {
-        self.focus = match self.focus {
-            DialogFocus::Content if !self.buttons.is_empty() => DialogFocus::Button(0),
-            DialogFocus::Content => DialogFocus::Content,
-            DialogFocus::Button(n) if n == self.buttons.len() - 1 => DialogFocus::Content,
-            DialogFocus::Button(n) => DialogFocus::Button(n + 1),
+    pub fn content(&self) -> &C {
+        self.content.inner()
+    }
+
+    #[inline]
+    pub fn content_mut(&mut self) -> &mut C {
+        self.content.inner_mut()
+    }
+}
+
+impl<S, E, M, C> DialogView<S, E, M, C>
+where
+    S: RedrawState + 'static,
+    C: View<S, E, Message = M>,
+    E: EventLike + 'static,
+    M: 'static,
+{
+    #[inline]
+    fn item_count(&self) -> usize {
+        self.buttons.len() + 1
+    }
+
+    #[inline]
+    fn item_focus(index: usize) -> DialogFocus {
+        match index {
+            0 => DialogFocus::Content,
+            n => DialogFocus::Button(n - 1),
+        }
+    }
+
+    #[inline]
+    fn item_mut(
+        &mut self,
+        focus: DialogFocus,
+    ) -> &mut dyn View<S, E, Message = M> {
+        match focus {
+            DialogFocus::Content => &mut self.content,
+            DialogFocus::Button(n) => &mut self.buttons[n],
+        }
+    }
+
+    fn focused_item(&self) -> Option<usize> {
+        if self.content.has_focus() {
+            Some(0)
+        } else {
+            self.buttons
+                .iter()
+                .position(|btn| btn.has_focus())
+                .map(|n| n + 1)
+        }
+    }
+
+    /// Move focus to next item which takes it, wraps around at the end when `wrap` is set
+    fn step_focus(
+        &mut self,
+        direction: FocusDirection,
+        wrap: bool,
+    ) -> bool {
+        let current = self.focused_item();
+        let len = self.item_count();
+        let take = |i: usize, direction| self.item_mut(Self::item_focus(i)).take_focus(direction);
+
+        let next = if wrap {
+            cycle_focus(len, current, direction, take)
+        } else {
+            walk_focus(len, current, direction, take)
         };
+
+        match next {
+            Some(i) => {
+                self.focus = Self::item_focus(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Give focus to clicked item, returns `true` when focus is changed
+    fn focus_item(
+        &mut self,
+        focus: DialogFocus,
+    ) -> bool {
+        let changed = self.focus != focus;
+        self.focus = focus;
+
+        if self.item_mut(focus).has_focus() {
+            return changed;
+        }
+
+        self.release_focus();
+        self.item_mut(focus).take_focus(FocusDirection::Forward);
+        true
     }
 }
 
@@ -116,6 +246,11 @@ where
         printer.with_style(Style::title(), |printer| {
             printer.print((0, 0), &self.title);
         });
+        if let Some(ref error) = self.error {
+            printer.with_style(Style::error(), |printer| {
+                printer.print((0, printer.bound().h().saturating_sub(1)), error);
+            });
+        }
         printer.with_style(Style::view(), |printer| {
             printer.with_bound(printer.bound().with_margin(1), |printer| {
                 let btn_height = 1;
@@ -130,13 +265,13 @@ where
                 let mut x = 0;
 
                 printer.with_bound(btns_bound, |printer| {
-                    for (i, btn) in self.buttons.iter().enumerate() {
-                        if self.focus == DialogFocus::Button(i) {
+                    for btn in self.buttons.iter() {
+                        if btn.has_focus() {
                             printer.with_style(Style::highlight(), |printer| {
-                                printer.print((x, 0), btn.text());
+                                printer.print_styled_text((x, 0), btn.styled_text());
                             });
                         } else {
-                            printer.print((x, 0), btn.text());
+                            printer.print_styled_text((x, 0), btn.styled_text());
                         }
                         x += btn.width();
                     }
@@ -162,6 +297,46 @@ where
         Vec2::new(content.x.max(buttons), content.y + 1) + Vec2::new(2, 2)
     }
 
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        self.step_focus(direction, false)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused_item().is_some()
+    }
+
+    fn release_focus(&mut self) {
+        self.content.release_focus();
+        for btn in self.buttons.iter_mut() {
+            btn.release_focus();
+        }
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        matches!(self.focus, DialogFocus::Content) && self.content.has_pending_keys()
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        match self.focused_item()? {
+            0 => {
+                let rect = self.content.focus_rect()?;
+                Some(Rect::new(rect.start() + Vec2::new(1, 1), rect.size()))
+            }
+            n => {
+                // Buttons are on the row below content
+                let x = self.buttons[..n - 1].iter().map(|b| b.width()).sum::<u16>();
+                let y = self.content.prev_size().y + 1;
+                Some(Rect::new((x + 1, y), (self.buttons[n - 1].width(), 1)))
+            }
+        }
+    }
+
     fn on_event(
         &mut self,
         state: &mut S,
@@ -180,13 +355,15 @@ where
 
             if is_btn {
                 let mut x = me.pos().x;
-                for (i, btn) in self.buttons.iter_mut().enumerate() {
-                    if btn.width() > x {
-                        state.set_need_redraw(self.focus == DialogFocus::Button(i));
-                        self.focus = DialogFocus::Button(i);
-                        return btn.on_event(state, event);
+                for i in 0..self.buttons.len() {
+                    let width = self.buttons[i].width();
+                    if width > x {
+                        if self.focus_item(DialogFocus::Button(i)) {
+                            state.set_need_redraw(true);
+                        }
+                        return self.buttons[i].on_event(state, event);
                     } else {
-                        x -= btn.width();
+                        x -= width;
                     }
                 }
 
@@ -203,38 +380,40 @@ where
             });
 
             if is_content {
-                state.set_need_redraw(self.focus == DialogFocus::Content);
-                self.focus = DialogFocus::Content;
+                if self.focus_item(DialogFocus::Content) {
+                    state.set_need_redraw(true);
+                }
                 self.content.on_event(state, event)
             } else {
                 None
             }
         } else if let Some(ke) = event.try_key() {
             if ke.try_tab() {
-                self.tab();
-                state.set_need_redraw(true);
+                if self.step_focus(FocusDirection::Forward, true) {
+                    state.set_need_redraw(true);
+                }
                 None
-            } else if self.focus == DialogFocus::Content {
-                self.content.on_event(state, event)
-            } else if let DialogFocus::Button(x) = &mut self.focus {
+            } else if ke.try_back_tab() {
+                if self.step_focus(FocusDirection::Backward, true) {
+                    state.set_need_redraw(true);
+                }
+                None
+            } else if let DialogFocus::Button(x) = self.focus {
                 if ke.try_left() {
-                    if let Some(new_x) = x.checked_sub(1) {
-                        *x = new_x;
+                    if x > 0 && self.focus_item(DialogFocus::Button(x - 1)) {
                         state.set_need_redraw(true);
                     }
                     None
                 } else if ke.try_right() {
-                    let new_x = *x + 1;
-                    if new_x < self.buttons.len() {
-                        *x = new_x;
+                    if x + 1 < self.buttons.len() && self.focus_item(DialogFocus::Button(x + 1)) {
                         state.set_need_redraw(true);
                     }
                     None
                 } else {
-                    self.buttons[*x].on_event(state, event)
+                    self.buttons[x].on_event(state, event)
                 }
             } else {
-                None
+                self.content.on_event(state, event)
             }
         } else {
             None
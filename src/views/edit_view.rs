@@ -1,35 +1,536 @@
 use crate::{
     event::{
         EventLike,
+        KeyCode,
         KeyEventLike,
+        KeyModifier,
+        MouseEventLike,
     },
+    focus::FocusDirection,
     printer::Printer,
     state::RedrawState,
-    style::Style,
+    style::{
+        Effect,
+        Style,
+    },
+    util::{
+        next_char_pos,
+        next_word_pos,
+        prev_char_pos,
+        prev_word_pos,
+    },
     vec2::Vec2,
     view::View,
 };
-use std::marker::PhantomData;
-use unicode_width::UnicodeWidthChar;
+use std::{
+    marker::PhantomData,
+    mem::replace,
+    ops::Range,
+};
+use unicode_segmentation::{
+    GraphemeCursor,
+    UnicodeSegmentation,
+};
+use unicode_width::{
+    UnicodeWidthChar,
+    UnicodeWidthStr,
+};
+
+/// Max number of undo steps
+const UNDO_LIMIT: usize = 100;
+
+#[derive(Clone, Debug)]
+struct Snapshot {
+    text:   String,
+    cursor: usize,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum EditKind {
+    Insert,
+    Delete,
+    Replace,
+}
+
+type Filter = Box<dyn Fn(char) -> bool>;
+type Validator = Box<dyn Fn(&str) -> Result<(), String>>;
 
 pub struct EditView<S, E> {
-    text:    String,
-    width:   usize,
-    _marker: PhantomData<(S, E)>,
+    text:        String,
+    placeholder: String,
+    /// Render `*` instead of characters
+    password:    bool,
+    /// Max number of characters
+    max_len:     usize,
+    filter:      Option<Filter>,
+    validator:   Option<Validator>,
+    error:       Option<String>,
+    /// Byte offset of cursor
+    cursor:      usize,
+    /// Other end of selection
+    anchor:      Option<usize>,
+    /// Scrolled columns
+    scroll:      usize,
+    width:       u16,
+    undo:        Vec<Snapshot>,
+    redo:        Vec<Snapshot>,
+    /// Kind of last edit, consecutive inserts or deletes are merged into one undo step until
+    /// cursor moves or a new word is started
+    last_edit:   Option<EditKind>,
+    focused:     bool,
+    _marker:     PhantomData<(S, E)>,
+}
+
+impl<S, E> Default for EditView<S, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<S, E> EditView<S, E> {
     pub fn new() -> Self {
         Self {
-            text:    String::new(),
-            width:   0,
-            _marker: PhantomData,
+            text:        String::new(),
+            placeholder: String::new(),
+            password:    false,
+            max_len:     usize::MAX,
+            filter:      None,
+            validator:   None,
+            error:       None,
+            cursor:      0,
+            anchor:      None,
+            scroll:      0,
+            width:       0,
+            undo:        Vec::new(),
+            redo:        Vec::new(),
+            last_edit:   None,
+            focused:     false,
+            _marker:     PhantomData,
         }
     }
 
     pub fn text(&self) -> &str {
         &self.text
     }
+
+    /// Replace text and clear undo history, cursor goes to the end
+    pub fn set_text(
+        &mut self,
+        text: impl Into<String>,
+    ) {
+        self.text = text.into();
+        self.cursor = self.text.len();
+        self.anchor = None;
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = None;
+        self.validate();
+        self.scroll_to_cursor();
+    }
+
+    /// Text shown when nothing is typed
+    #[inline]
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<String>,
+    ) -> Self {
+        self.set_placeholder(placeholder);
+        self
+    }
+
+    #[inline]
+    pub fn set_placeholder(
+        &mut self,
+        placeholder: impl Into<String>,
+    ) {
+        self.placeholder = placeholder.into();
+    }
+
+    /// Mask every grapheme cluster with `*`, `text` still returns the real text
+    #[inline]
+    pub fn password(
+        mut self,
+        password: bool,
+    ) -> Self {
+        self.set_password(password);
+        self
+    }
+
+    #[inline]
+    pub fn set_password(
+        &mut self,
+        password: bool,
+    ) {
+        self.password = password;
+        self.scroll_to_cursor();
+    }
+
+    /// Limit number of grapheme clusters, further input is rejected
+    #[inline]
+    pub fn max_len(
+        mut self,
+        max_len: usize,
+    ) -> Self {
+        self.set_max_len(max_len);
+        self
+    }
+
+    #[inline]
+    pub fn set_max_len(
+        &mut self,
+        max_len: usize,
+    ) {
+        self.max_len = max_len;
+    }
+
+    /// Only accept characters which `filter` returns `true`
+    #[inline]
+    pub fn filter(
+        mut self,
+        filter: impl Fn(char) -> bool + 'static,
+    ) -> Self {
+        self.set_filter(filter);
+        self
+    }
+
+    #[inline]
+    pub fn set_filter(
+        &mut self,
+        filter: impl Fn(char) -> bool + 'static,
+    ) {
+        self.filter = Some(Box::new(filter));
+    }
+
+    /// Check text after every edit, error is available with [`EditView::error`]
+    ///
+    /// Submit is ignored while text is invalid.
+    #[inline]
+    pub fn validator(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.set_validator(validator);
+        self
+    }
+
+    #[inline]
+    pub fn set_validator(
+        &mut self,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+    ) {
+        self.validator = Some(Box::new(validator));
+        self.validate();
+    }
+
+    /// Error message from validator
+    #[inline]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    fn validate(&mut self) {
+        self.error = match self.validator {
+            Some(ref validator) => validator(&self.text).err(),
+            None => None,
+        };
+    }
+
+    /// Byte offset of cursor
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Move cursor and clear selection, out of range position is clamped
+    pub fn set_cursor(
+        &mut self,
+        pos: usize,
+    ) {
+        self.anchor = None;
+        self.move_cursor(pos, false);
+    }
+
+    /// Byte range of selected text
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+
+        if anchor < self.cursor {
+            Some(anchor..self.cursor)
+        } else if anchor > self.cursor {
+            Some(self.cursor..anchor)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn selected_text(&self) -> &str {
+        self.selection().map_or("", |range| &self.text[range])
+    }
+
+    /// Select byte range, cursor goes to the end of it
+    pub fn select(
+        &mut self,
+        range: Range<usize>,
+    ) {
+        self.set_cursor(range.start);
+        self.move_cursor(range.end, true);
+    }
+
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.redo.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.undo.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn restore(
+        &mut self,
+        snapshot: Snapshot,
+    ) -> Snapshot {
+        let text = replace(&mut self.text, snapshot.text);
+        let cursor = replace(&mut self.cursor, snapshot.cursor);
+        self.anchor = None;
+        self.last_edit = None;
+        self.validate();
+        self.scroll_to_cursor();
+        Snapshot { text, cursor }
+    }
+
+    /// Save undo snapshot before modifying text
+    fn begin_edit(
+        &mut self,
+        kind: EditKind,
+    ) {
+        if kind == EditKind::Replace || self.last_edit != Some(kind) {
+            if self.undo.len() == UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+
+            self.undo.push(Snapshot {
+                text:   self.text.clone(),
+                cursor: self.cursor,
+            });
+        }
+
+        self.redo.clear();
+        self.last_edit = Some(kind);
+    }
+
+    fn move_cursor(
+        &mut self,
+        pos: usize,
+        extend: bool,
+    ) -> bool {
+        let mut pos = pos.min(self.text.len());
+
+        while !self.text.is_char_boundary(pos) {
+            pos -= 1;
+        }
+
+        let old = (self.cursor, self.anchor);
+
+        if extend {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+
+        self.cursor = pos;
+        self.last_edit = None;
+        self.scroll_to_cursor();
+
+        old != (self.cursor, self.anchor)
+    }
+
+    /// Move cursor by key, moving without `Shift` collapses selection to its side
+    fn step_cursor(
+        &mut self,
+        pos: usize,
+        extend: bool,
+        forward: bool,
+    ) -> bool {
+        match self.selection() {
+            Some(range) if !extend => {
+                let pos = if forward { range.end } else { range.start };
+                self.move_cursor(pos, false)
+            }
+            _ => self.move_cursor(pos, extend),
+        }
+    }
+
+    fn delete_range(
+        &mut self,
+        range: Range<usize>,
+    ) {
+        self.text.replace_range(range.clone(), "");
+        self.cursor = range.start;
+        self.anchor = None;
+        self.scroll_to_cursor();
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some(range) => {
+                self.begin_edit(EditKind::Replace);
+                self.delete_range(range);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `false` when character is rejected by filter or length limit
+    fn insert_char(
+        &mut self,
+        ch: char,
+    ) -> bool {
+        if let Some(ref filter) = self.filter {
+            if !filter(ch) {
+                return false;
+            }
+        }
+
+        // Combining characters join the cluster before them so check the resulting text
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        let mut text = self.text.clone();
+        text.replace_range(range, ch.encode_utf8(&mut [0; 4]));
+        if text.graphemes(true).count() > self.max_len {
+            return false;
+        }
+
+        // Each typed word is its own undo step
+        let prev = self.text[..self.cursor].chars().next_back();
+        if prev.is_some_and(char::is_whitespace) && !ch.is_whitespace() {
+            self.last_edit = None;
+        }
+
+        match self.selection() {
+            Some(range) => {
+                self.begin_edit(EditKind::Replace);
+                self.delete_range(range);
+            }
+            None => self.begin_edit(EditKind::Insert),
+        }
+
+        self.text.insert(self.cursor, ch);
+        self.cursor += ch.len_utf8();
+        self.anchor = None;
+        self.scroll_to_cursor();
+        true
+    }
+
+    /// Delete selection or text between cursor and `pos`
+    fn delete_to(
+        &mut self,
+        pos: usize,
+    ) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let range = if pos < self.cursor {
+            pos..self.cursor
+        } else {
+            self.cursor..pos
+        };
+
+        if range.start == range.end {
+            return false;
+        }
+
+        self.begin_edit(EditKind::Delete);
+        self.delete_range(range);
+        true
+    }
+
+    /// Width of a character at byte offset `pos` as rendered
+    #[inline]
+    fn char_width(
+        &self,
+        pos: usize,
+        ch: char,
+    ) -> usize {
+        if self.password {
+            // Only the first character of a cluster is masked
+            let mut cursor = GraphemeCursor::new(pos, self.text.len(), true);
+            return usize::from(cursor.is_boundary(&self.text, 0).unwrap_or(true));
+        }
+
+        ch.width().unwrap_or(0)
+    }
+
+    /// Width of a text as rendered
+    #[inline]
+    fn str_width(
+        &self,
+        text: &str,
+    ) -> usize {
+        if self.password {
+            text.graphemes(true).count()
+        } else {
+            text.width()
+        }
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let col = self.str_width(&self.text[..self.cursor]);
+        let cursor_width = self.text[self.cursor..]
+            .chars()
+            .next()
+            .map_or(1, |ch| self.char_width(self.cursor, ch))
+            .max(1);
+        let width = self.width.max(1) as usize;
+
+        // Don't leave empty space after shrinking text
+        self.scroll = self
+            .scroll
+            .min((self.str_width(&self.text) + 1).saturating_sub(width));
+
+        if col < self.scroll {
+            self.scroll = col;
+        } else if col + cursor_width > self.scroll + width {
+            self.scroll = (col + cursor_width).saturating_sub(width);
+        }
+    }
+
+    /// Byte offset of the cell at `x` in view coordinate
+    fn pos_at(
+        &self,
+        x: u16,
+    ) -> usize {
+        let mut col = self.scroll + x as usize;
+
+        for (i, ch) in self.text.char_indices() {
+            match col.checked_sub(self.char_width(i, ch)) {
+                Some(left) => col = left,
+                None => return i,
+            }
+        }
+
+        self.text.len()
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -42,47 +543,287 @@ impl<S: RedrawState, E: EventLike> View<S, E> for EditView<S, E> {
     type Message = EditViewMessage;
 
     fn desired_size(&self) -> Vec2 {
-        Vec2::new(self.width as u16, 1)
+        let width = self.str_width(&self.text).max(self.placeholder.width());
+        // Extra cell for cursor
+        Vec2::new(width as u16 + 1, 1)
     }
 
     fn layout(
         &mut self,
-        _size: Vec2,
+        size: Vec2,
     ) {
+        self.width = size.x;
+        self.scroll_to_cursor();
     }
 
     fn render(
         &self,
         printer: &mut Printer,
     ) {
-        printer.with_style(Style::view(), |printer| {
-            printer.print((0, 0), &self.text);
+        let selection = self.selection().unwrap_or(0..0);
+        let highlight = if self.focused {
+            Style::highlight()
+        } else {
+            Style::highlight_inactive()
+        };
+        let (style, cursor_style) = if self.error.is_some() {
+            (Style::error(), Style::error().effects(Effect::Reverse))
+        } else {
+            (Style::view(), Style::view().effects(Effect::Reverse))
+        };
+        let mut buf = [0; 4];
+
+        if self.text.is_empty() && !self.placeholder.is_empty() {
+            printer.with_style(Style::secondary(), |printer| {
+                printer.print((0, 0), &self.placeholder);
+
+                if self.focused {
+                    let ch = self.placeholder.chars().next().unwrap_or(' ');
+                    printer.with_style(Style::secondary().effects(Effect::Reverse), |printer| {
+                        printer.print((0, 0), ch.encode_utf8(&mut buf));
+                    });
+                }
+            });
+            return;
+        }
+
+        printer.with_style(style, |printer| {
+            let mut col = 0;
+
+            let chars = self.text.char_indices().chain(Some((self.text.len(), ' ')));
+
+            for (i, ch) in chars {
+                let width = self.char_width(i, ch);
+                let start = col;
+                col += width;
+
+                // Skip scrolled and cut characters
+                if start < self.scroll || (width == 0 && self.password) {
+                    continue;
+                }
+
+                let x = (start - self.scroll) as u16;
+
+                if x >= self.width {
+                    break;
+                }
+
+                let ch_style = if self.focused && i == self.cursor {
+                    cursor_style
+                } else if selection.contains(&i) {
+                    highlight
+                } else if i == self.text.len() {
+                    break;
+                } else {
+                    style
+                };
+
+                let ch = if self.password && i != self.text.len() {
+                    '*'
+                } else {
+                    ch
+                };
+
+                printer.with_style(ch_style, |printer| {
+                    printer.print((x, 0), ch.encode_utf8(&mut buf));
+                });
+            }
         });
     }
 
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
     fn on_event(
         &mut self,
         state: &mut S,
         e: E,
     ) -> Option<Self::Message> {
+        if let Some(me) = e.try_mouse() {
+            let moved = if let Some(pos) = me.try_left_down() {
+                self.move_cursor(self.pos_at(pos.x), false)
+            } else if let Some(pos) = me.try_drag() {
+                self.move_cursor(self.pos_at(pos.x), true)
+            } else {
+                false
+            };
+
+            if moved {
+                state.set_need_redraw(true);
+            }
+
+            return None;
+        }
+
         let ke = e.try_key()?;
-        if ke.try_enter() {
-            Some(EditViewMessage::Submit)
+        let shift = ke.modifiers().contains(KeyModifier::Shift);
+        let only = |m: KeyModifier| (ke.modifiers() - KeyModifier::Shift) == m;
+        let plain = (ke.modifiers() - KeyModifier::Shift).is_empty();
+
+        let edited = if ke.try_enter() {
+            return if self.is_valid() {
+                Some(EditViewMessage::Submit)
+            } else {
+                None
+            };
         } else if let Some(ch) = ke.try_char() {
-            self.text.push(ch);
-            self.width += ch.width().unwrap_or(0);
-            state.set_need_redraw(true);
-            Some(EditViewMessage::Edit)
+            self.insert_char(ch)
         } else if ke.try_backspace() {
-            if let Some(ch) = self.text.pop() {
-                self.width -= ch.width().unwrap_or(0);
-                state.set_need_redraw(true);
-                Some(EditViewMessage::Edit)
+            self.delete_to(prev_char_pos(&self.text, self.cursor))
+        } else if ke.try_delete() {
+            self.delete_to(next_char_pos(&self.text, self.cursor))
+        } else if ke.try_ctrl_char() == Some('w') || ke.is_key(KeyCode::Backspace, KeyModifier::Alt)
+        {
+            self.delete_to(prev_word_pos(&self.text, self.cursor))
+        } else if ke.try_ctrl_char() == Some('z') {
+            self.undo()
+        } else if ke.try_ctrl_char() == Some('y') {
+            self.redo()
+        } else {
+            let code = ke.code();
+
+            let moved = if code == KeyCode::Left && plain {
+                self.step_cursor(prev_char_pos(&self.text, self.cursor), shift, false)
+            } else if code == KeyCode::Right && plain {
+                self.step_cursor(next_char_pos(&self.text, self.cursor), shift, true)
+            } else if code == KeyCode::Left && only(KeyModifier::Control) {
+                self.move_cursor(prev_word_pos(&self.text, self.cursor), shift)
+            } else if code == KeyCode::Right && only(KeyModifier::Control) {
+                self.move_cursor(next_word_pos(&self.text, self.cursor), shift)
+            } else if code == KeyCode::Home && plain {
+                self.move_cursor(0, shift)
+            } else if code == KeyCode::End && plain {
+                self.move_cursor(self.text.len(), shift)
             } else {
-                None
+                return None;
+            };
+
+            if moved {
+                state.set_need_redraw(true);
             }
+
+            return None;
+        };
+
+        if edited {
+            self.validate();
+            state.set_need_redraw(true);
+            Some(EditViewMessage::Edit)
         } else {
             None
         }
     }
 }
+
+#[test]
+fn edit_view_undo_test() {
+    let mut view = EditView::<(), ()>::new();
+
+    for ch in "foo bar".chars() {
+        view.insert_char(ch);
+    }
+
+    view.delete_to(prev_word_pos(&view.text, view.cursor));
+    assert_eq!(view.text(), "foo ");
+    view.insert_char('x');
+    assert_eq!(view.text(), "foo x");
+
+    assert!(view.undo());
+    assert_eq!(view.text(), "foo ");
+    assert!(view.undo());
+    assert_eq!(view.text(), "foo bar");
+    assert!(view.undo());
+    assert_eq!(view.text(), "foo ");
+    assert!(view.undo());
+    assert_eq!(view.text(), "");
+    assert!(!view.undo());
+
+    assert!(view.redo());
+    assert_eq!(view.text(), "foo ");
+    assert_eq!(view.cursor(), 4);
+}
+
+#[test]
+fn edit_view_undo_cursor_move_test() {
+    let mut view = EditView::<(), ()>::new();
+
+    for ch in "ab".chars() {
+        view.insert_char(ch);
+    }
+    view.move_cursor(1, false);
+    view.insert_char('x');
+    view.insert_char('y');
+    assert_eq!(view.text(), "axyb");
+
+    assert!(view.undo());
+    assert_eq!(view.text(), "ab");
+    assert!(view.undo());
+    assert_eq!(view.text(), "");
+}
+
+#[test]
+fn edit_view_selection_test() {
+    let mut view = EditView::<(), ()>::new();
+    view.set_text("hello world");
+    view.select(0..5);
+    assert_eq!(view.selected_text(), "hello");
+
+    view.insert_char('H');
+    assert_eq!(view.text(), "H world");
+    assert_eq!(view.selection(), None);
+
+    view.move_cursor(0, true);
+    assert_eq!(view.selected_text(), "H");
+    assert!(view.delete_to(view.cursor));
+    assert_eq!(view.text(), " world");
+}
+
+#[test]
+fn edit_view_filter_test() {
+    let mut view = EditView::<(), ()>::new()
+        .filter(|ch| ch.is_ascii_digit())
+        .max_len(3);
+
+    for ch in "1a2b34".chars() {
+        view.insert_char(ch);
+    }
+
+    assert_eq!(view.text(), "123");
+
+    view.select(0..1);
+    assert!(view.insert_char('9'));
+    assert_eq!(view.text(), "923");
+}
+
+#[test]
+fn edit_view_validator_test() {
+    let mut view = EditView::<(), ()>::new().validator(|text| {
+        if text.len() < 2 {
+            Err("too short".into())
+        } else {
+            Ok(())
+        }
+    });
+
+    assert_eq!(view.error(), Some("too short"));
+    view.set_text("ab");
+    assert!(view.is_valid());
+    view.set_text("a");
+    assert_eq!(view.error(), Some("too short"));
+}
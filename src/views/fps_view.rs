@@ -15,6 +15,13 @@ pub struct FpsView<S, E> {
     _marker:   PhantomData<(S, E)>,
 }
 
+impl<S, E> Default for FpsView<S, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<S, E> FpsView<S, E> {
     pub fn new() -> Self {
         Self {
@@ -0,0 +1,469 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyEventLike,
+        MouseEventLike,
+    },
+    focus::{
+        cycle_focus,
+        walk_focus,
+        FocusDirection,
+    },
+    layout::{
+        solve,
+        Constraint,
+    },
+    printer::Printer,
+    rect::Rect,
+    state::RedrawState,
+    vec2::Vec2,
+    view::View,
+    view_wrappers::SizeCacher,
+};
+
+struct GridChild<S, E, M> {
+    view:     SizeCacher<Box<dyn View<S, E, Message = M> + 'static>>,
+    row:      usize,
+    col:      usize,
+    row_span: usize,
+    col_span: usize,
+    pos:      Vec2,
+}
+
+impl<S, E, M> GridChild<S, E, M> {
+    #[inline]
+    fn rect(&self) -> Rect {
+        Rect::new(self.pos, self.view.prev_size())
+    }
+}
+
+/// Places children in cells of rows and columns
+///
+/// Tracks are sized with [`Constraint`], use [`Constraint::Fill`] for fractions of free space.
+/// Tracks which are not declared are added as [`Constraint::Auto`] when a child is placed on
+/// them. Focus moves over children in reading order.
+pub struct GridView<S, E, M> {
+    children: Vec<GridChild<S, E, M>>,
+    rows:     Vec<Constraint>,
+    cols:     Vec<Constraint>,
+    row_gap:  u16,
+    col_gap:  u16,
+}
+
+impl<S, E, M> Default for GridView<S, E, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, E, M> GridView<S, E, M> {
+    pub fn new() -> Self {
+        Self {
+            children: Vec::with_capacity(10),
+            rows:     Vec::new(),
+            cols:     Vec::new(),
+            row_gap:  0,
+            col_gap:  0,
+        }
+    }
+
+    #[inline]
+    pub fn rows(
+        mut self,
+        rows: impl IntoIterator<Item = Constraint>,
+    ) -> Self {
+        self.set_rows(rows);
+        self
+    }
+
+    #[inline]
+    pub fn set_rows(
+        &mut self,
+        rows: impl IntoIterator<Item = Constraint>,
+    ) {
+        self.rows = rows.into_iter().collect();
+        self.extend_tracks();
+    }
+
+    #[inline]
+    pub fn columns(
+        mut self,
+        cols: impl IntoIterator<Item = Constraint>,
+    ) -> Self {
+        self.set_columns(cols);
+        self
+    }
+
+    #[inline]
+    pub fn set_columns(
+        &mut self,
+        cols: impl IntoIterator<Item = Constraint>,
+    ) {
+        self.cols = cols.into_iter().collect();
+        self.extend_tracks();
+    }
+
+    /// Empty cells between rows and between columns
+    #[inline]
+    pub fn gap(
+        mut self,
+        row_gap: u16,
+        col_gap: u16,
+    ) -> Self {
+        self.set_gap(row_gap, col_gap);
+        self
+    }
+
+    #[inline]
+    pub fn set_gap(
+        &mut self,
+        row_gap: u16,
+        col_gap: u16,
+    ) {
+        self.row_gap = row_gap;
+        self.col_gap = col_gap;
+    }
+
+    #[inline]
+    pub fn child(
+        self,
+        row: usize,
+        col: usize,
+        v: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.child_span(row, col, 1, 1, v)
+    }
+
+    #[inline]
+    pub fn add_child(
+        &mut self,
+        row: usize,
+        col: usize,
+        v: impl View<S, E, Message = M> + 'static,
+    ) {
+        self.add_child_span(row, col, 1, 1, v);
+    }
+
+    #[inline]
+    pub fn child_span(
+        mut self,
+        row: usize,
+        col: usize,
+        row_span: usize,
+        col_span: usize,
+        v: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.add_child_span(row, col, row_span, col_span, v);
+        self
+    }
+
+    /// Place child at `row`, `col` covering `row_span` rows and `col_span` columns
+    pub fn add_child_span(
+        &mut self,
+        row: usize,
+        col: usize,
+        row_span: usize,
+        col_span: usize,
+        v: impl View<S, E, Message = M> + 'static,
+    ) {
+        // Keep reading order for focus
+        let index = self
+            .children
+            .partition_point(|c| (c.row, c.col) <= (row, col));
+
+        self.children.insert(index, GridChild {
+            view: SizeCacher::new(Box::new(v)),
+            row,
+            col,
+            row_span: row_span.max(1),
+            col_span: col_span.max(1),
+            pos: Vec2::new(0, 0),
+        });
+        self.extend_tracks();
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Add `Auto` tracks so every child is covered
+    fn extend_tracks(&mut self) {
+        for child in self.children.iter() {
+            let rows = child.row + child.row_span;
+            let cols = child.col + child.col_span;
+
+            if self.rows.len() < rows {
+                self.rows.resize(rows, Constraint::Auto);
+            }
+            if self.cols.len() < cols {
+                self.cols.resize(cols, Constraint::Auto);
+            }
+        }
+    }
+
+    /// Desired size of each row and column from children
+    ///
+    /// Spanning children put what doesn't fit into their spanned tracks on the last one.
+    fn track_desired(&self) -> (Vec<u16>, Vec<u16>) {
+        let mut rows = vec![0u16; self.rows.len()];
+        let mut cols = vec![0u16; self.cols.len()];
+
+        // Single cell children first so spans only add what is missing
+        let mut children: Vec<&GridChild<S, E, M>> = self.children.iter().collect();
+        children.sort_by_key(|c| c.row_span.max(c.col_span));
+
+        for child in children {
+            let desired = child.view.desired_size();
+            fit(
+                &mut rows,
+                child.row,
+                child.row_span,
+                self.row_gap,
+                desired.y,
+            );
+            fit(
+                &mut cols,
+                child.col,
+                child.col_span,
+                self.col_gap,
+                desired.x,
+            );
+        }
+
+        (rows, cols)
+    }
+
+    #[inline]
+    fn focused_child(&self) -> Option<usize> {
+        self.children.iter().position(|c| c.view.has_focus())
+    }
+
+    /// Give focus to child which is clicked, focus is kept when it doesn't take focus
+    fn focus_child(
+        &mut self,
+        index: usize,
+    ) -> bool {
+        if self.children[index].view.has_focus()
+            || !self.children[index]
+                .view
+                .take_focus(FocusDirection::Forward)
+        {
+            return false;
+        }
+
+        for (i, child) in self.children.iter_mut().enumerate() {
+            if i != index {
+                child.view.release_focus();
+            }
+        }
+
+        true
+    }
+
+    /// Move focus to next child which takes it, wraps around at the end when `wrap` is set
+    fn step_focus(
+        &mut self,
+        direction: FocusDirection,
+        wrap: bool,
+    ) -> bool {
+        let current = self.focused_child();
+        let len = self.children.len();
+        let children = &mut self.children;
+        let take = |i: usize, direction| children[i].view.take_focus(direction);
+
+        if wrap {
+            cycle_focus(len, current, direction, take).is_some()
+        } else {
+            walk_focus(len, current, direction, take).is_some()
+        }
+    }
+}
+
+/// Grow last of `span` tracks from `start` until they fit `desired` with gaps
+fn fit(
+    tracks: &mut [u16],
+    start: usize,
+    span: usize,
+    gap: u16,
+    desired: u16,
+) {
+    let spanned = &mut tracks[start..start + span];
+    let gaps = gap.saturating_mul(span as u16 - 1);
+    let total = spanned
+        .iter()
+        .fold(gaps, |acc, &len| acc.saturating_add(len));
+
+    if let Some(rest) = desired.checked_sub(total) {
+        let last = spanned.last_mut().unwrap();
+        *last = last.saturating_add(rest);
+    }
+}
+
+/// Sizes and offsets of tracks which share `available` cells
+fn place_tracks(
+    constraints: &[Constraint],
+    desired: &[u16],
+    gap: u16,
+    available: u16,
+) -> Vec<(u16, u16)> {
+    let gaps = gap.saturating_mul(constraints.len().saturating_sub(1) as u16);
+    let items: Vec<(Constraint, u16)> = constraints
+        .iter()
+        .copied()
+        .zip(desired.iter().copied())
+        .collect();
+
+    let mut offset = 0u16;
+
+    solve(&items, available.saturating_sub(gaps))
+        .into_iter()
+        .map(|len| {
+            let start = offset;
+            offset = offset.saturating_add(len).saturating_add(gap);
+            (start, len)
+        })
+        .collect()
+}
+
+/// Offset and length of `span` tracks from `start`
+#[inline]
+fn span_range(
+    tracks: &[(u16, u16)],
+    start: usize,
+    span: usize,
+) -> (u16, u16) {
+    let (offset, _) = tracks[start];
+    let (last, len) = tracks[start + span - 1];
+    (offset, last + len - offset)
+}
+
+impl<S: RedrawState, E: EventLike, M> View<S, E> for GridView<S, E, M> {
+    type Message = M;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let start = printer.bound().start();
+
+        for child in self.children.iter() {
+            let rect = child.rect();
+            printer.with_bound(Rect::new(start + rect.start(), rect.size()), |printer| {
+                child.view.render(printer)
+            });
+        }
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        let (rows, cols) = self.track_desired();
+
+        let total = |constraints: &[Constraint], desired: &[u16], gap: u16| {
+            let gaps = gap.saturating_mul(constraints.len().saturating_sub(1) as u16);
+            constraints
+                .iter()
+                .zip(desired)
+                .fold(gaps, |acc, (c, &d)| acc.saturating_add(c.desired(d)))
+        };
+
+        Vec2::new(
+            total(&self.cols, &cols, self.col_gap),
+            total(&self.rows, &rows, self.row_gap),
+        )
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        let (rows, cols) = self.track_desired();
+        let rows = place_tracks(&self.rows, &rows, self.row_gap, size.y);
+        let cols = place_tracks(&self.cols, &cols, self.col_gap, size.x);
+
+        for child in self.children.iter_mut() {
+            let (y, h) = span_range(&rows, child.row, child.row_span);
+            let (x, w) = span_range(&cols, child.col, child.col_span);
+
+            child.pos = Vec2::new(x, y);
+            child.view.layout(Vec2::new(w, h));
+        }
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        self.step_focus(direction, false)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused_child().is_some()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        for child in self.children.iter_mut() {
+            child.view.release_focus();
+        }
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.focused_child()
+            .is_some_and(|i| self.children[i].view.has_pending_keys())
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        let child = &self.children[self.focused_child()?];
+        let rect = child.view.focus_rect()?;
+        Some(Rect::new(child.pos + rect.start(), rect.size()))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        mut event: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = event.try_mouse_mut() {
+            let is_click = me.try_left_down().is_some();
+            let pos = me.pos();
+            let i = self.children.iter().position(|c| c.rect().contains(pos))?;
+            let start = self.children[i].pos;
+            me.map_pos(|pos| pos - start);
+
+            if is_click && self.focus_child(i) {
+                state.set_need_redraw(true);
+            }
+
+            self.children[i].view.on_event(state, event)
+        } else if let Some(ke) = event.try_key() {
+            let direction = if ke.try_tab() {
+                Some(FocusDirection::Forward)
+            } else if ke.try_back_tab() {
+                Some(FocusDirection::Backward)
+            } else {
+                None
+            };
+
+            if let Some(direction) = direction {
+                if self.step_focus(direction, true) {
+                    state.set_need_redraw(true);
+                    return None;
+                }
+            }
+
+            let i = self.focused_child()?;
+            self.children[i].view.on_event(state, event)
+        } else {
+            None
+        }
+    }
+}
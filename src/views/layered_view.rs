@@ -1,11 +1,73 @@
 use crate::{
+    event::{
+        EventLike,
+        KeyEventLike,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
     printer::Printer,
+    rect::Rect,
+    state::RedrawState,
     vec2::Vec2,
     view::View,
 };
 
+/// Where a layer of [`LayeredView`] is shown
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Placement {
+    /// Take the whole size of [`LayeredView`]
+    Fullscreen,
+    Center,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    /// Offset from top-left corner, moved towards the origin when layer doesn't fit
+    Absolute(Vec2),
+}
+
+impl Placement {
+    /// Bound of a layer with `desired` size inside `size`
+    fn rect(
+        self,
+        desired: Vec2,
+        size: Vec2,
+    ) -> Rect {
+        let layer_size = Vec2::new(desired.x.min(size.x), desired.y.min(size.y));
+        let free = size.saturating_sub(layer_size);
+
+        let pos = match self {
+            Placement::Fullscreen => return Rect::new((0, 0), size),
+            Placement::Center => Vec2::new(free.x / 2, free.y / 2),
+            Placement::TopLeft => Vec2::new(0, 0),
+            Placement::TopRight => Vec2::new(free.x, 0),
+            Placement::BottomLeft => Vec2::new(0, free.y),
+            Placement::BottomRight => free,
+            Placement::Absolute(pos) => Vec2::new(pos.x.min(free.x), pos.y.min(free.y)),
+        };
+
+        Rect::new(pos, layer_size)
+    }
+}
+
+struct Layer<S, E, M> {
+    view:      Box<dyn View<S, E, Message = M>>,
+    placement: Placement,
+    /// Bound after layout
+    rect:      Rect,
+    modal:     bool,
+    popup:     bool,
+}
+
 pub struct LayeredView<S, E, M> {
-    layers: Vec<Box<dyn View<S, E, Message = M>>>,
+    layers: Vec<Layer<S, E, M>>,
+}
+
+impl<S, E, M> Default for LayeredView<S, E, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<S, E, M> LayeredView<S, E, M> {
@@ -24,29 +86,213 @@ impl<S, E, M> LayeredView<S, E, M> {
         self
     }
 
+    /// Push new layer on top, it takes focus from previous top layer
     #[inline]
     pub fn add_layer(
         &mut self,
         layer: impl View<S, E, Message = M> + 'static,
     ) {
-        self.layers.push(Box::new(layer));
+        self.push(Box::new(layer), Placement::TopLeft, false, false);
+    }
+
+    #[inline]
+    pub fn layer_at(
+        mut self,
+        pos: impl Into<Vec2>,
+        layer: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.add_layer_at(pos, layer);
+        self
+    }
+
+    /// Same as [`LayeredView::add_layer`] but layer is shown at `pos`
+    ///
+    /// Layer is moved towards the origin when it doesn't fit on the screen.
+    #[inline]
+    pub fn add_layer_at(
+        &mut self,
+        pos: impl Into<Vec2>,
+        layer: impl View<S, E, Message = M> + 'static,
+    ) {
+        self.push(
+            Box::new(layer),
+            Placement::Absolute(pos.into()),
+            false,
+            false,
+        );
     }
 
     #[inline]
+    pub fn layer_placed(
+        mut self,
+        placement: Placement,
+        layer: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.add_layer_placed(placement, layer);
+        self
+    }
+
+    /// Same as [`LayeredView::add_layer`] but layer is shown at `placement`
+    #[inline]
+    pub fn add_layer_placed(
+        &mut self,
+        placement: Placement,
+        layer: impl View<S, E, Message = M> + 'static,
+    ) {
+        self.push(Box::new(layer), placement, false, false);
+    }
+
+    #[inline]
+    pub fn modal(
+        mut self,
+        placement: Placement,
+        layer: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.add_modal(placement, layer);
+        self
+    }
+
+    /// Show a layer which dims layers below it
+    ///
+    /// Mouse events outside of a modal layer are dropped while other layers pass them to the
+    /// layer under the cursor.
+    #[inline]
+    pub fn add_modal(
+        &mut self,
+        placement: Placement,
+        layer: impl View<S, E, Message = M> + 'static,
+    ) {
+        self.push(Box::new(layer), placement, true, false);
+    }
+
+    /// Show a layer at `pos` which is removed when it returns a message, on `Esc` or a click
+    /// outside of it
+    ///
+    /// Use with [`ContextMenuView`](crate::views::ContextMenuView) for context menus.
+    #[inline]
+    pub fn add_popup(
+        &mut self,
+        pos: impl Into<Vec2>,
+        layer: impl View<S, E, Message = M> + 'static,
+    ) {
+        self.push(
+            Box::new(layer),
+            Placement::Absolute(pos.into()),
+            false,
+            true,
+        );
+    }
+
+    fn push(
+        &mut self,
+        mut view: Box<dyn View<S, E, Message = M>>,
+        placement: Placement,
+        modal: bool,
+        popup: bool,
+    ) {
+        if let Some(top) = self.layers.last_mut() {
+            if top.view.has_focus() {
+                top.view.release_focus();
+                view.take_focus(FocusDirection::Forward);
+            }
+        }
+
+        self.layers.push(Layer {
+            view,
+            placement,
+            rect: Rect::new((0, 0), (0, 0)),
+            modal,
+            popup,
+        });
+    }
+
+    /// Remove top layer, focus goes back to the new top layer
     pub fn pop_layer(&mut self) -> Option<Box<dyn View<S, E, Message = M>>> {
-        self.layers.pop()
+        let mut layer = self.layers.pop()?.view;
+
+        if layer.has_focus() {
+            layer.release_focus();
+
+            if let Some(top) = self.layers.last_mut() {
+                if !top.view.has_focus() {
+                    top.view.take_focus(FocusDirection::Forward);
+                }
+            }
+        }
+
+        Some(layer)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Bound of layer at `index` after last layout
+    #[inline]
+    pub fn layer_rect(
+        &self,
+        index: usize,
+    ) -> Option<Rect> {
+        self.layers.get(index).map(|layer| layer.rect)
+    }
+}
+
+/// Move mouse position into `rect`, `false` when it is outside
+fn translate_mouse<E: EventLike>(
+    event: &mut E,
+    rect: Rect,
+) -> bool {
+    match event.try_mouse_mut() {
+        Some(me) => {
+            me.filter_map_pos(|pos| {
+                if rect.contains(pos) {
+                    Some(pos - rect.start())
+                } else {
+                    None
+                }
+            })
+        }
+        None => true,
     }
 }
 
-impl<S, E, M> View<S, E> for LayeredView<S, E, M> {
+impl<S: RedrawState, E: EventLike, M> View<S, E> for LayeredView<S, E, M> {
     type Message = M;
 
     fn render(
         &self,
         printer: &mut Printer,
     ) {
-        for layer in self.layers.iter() {
-            layer.render(printer);
+        let start = printer.bound().start();
+        let render_layer = |printer: &mut Printer, layer: &Layer<S, E, M>| {
+            printer.with_bound(
+                Rect::new(start + layer.rect.start(), layer.rect.size()),
+                |printer| layer.view.render(printer),
+            );
+        };
+
+        // Everything below top modal layer is dimmed
+        let backdrop = self
+            .layers
+            .iter()
+            .rposition(|layer| layer.modal)
+            .unwrap_or(0);
+        let (below, above) = self.layers.split_at(backdrop);
+
+        printer.dimmed(|printer| {
+            for layer in below {
+                render_layer(printer, layer);
+            }
+        });
+
+        for layer in above {
+            render_layer(printer, layer);
         }
     }
 
@@ -55,23 +301,115 @@ impl<S, E, M> View<S, E> for LayeredView<S, E, M> {
         size: Vec2,
     ) {
         for layer in self.layers.iter_mut() {
-            layer.layout(size.min(layer.desired_size()));
+            layer.rect = layer.placement.rect(layer.view.desired_size(), size);
+            layer.view.layout(layer.rect.size());
         }
     }
 
     fn desired_size(&self) -> Vec2 {
         self.layers
             .iter()
-            .map(|layer| layer.desired_size())
-            .max()
-            .unwrap_or(Vec2::new(0, 0))
+            .map(|layer| {
+                match layer.placement {
+                    Placement::Absolute(pos) => layer.view.desired_size() + pos,
+                    _ => layer.view.desired_size(),
+                }
+            })
+            .fold(Vec2::new(0, 0), |acc, size| {
+                Vec2::new(acc.x.max(size.x), acc.y.max(size.y))
+            })
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        match self.layers.last_mut() {
+            Some(top) => top.view.take_focus(direction),
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.layers.last().is_some_and(|top| top.view.has_focus())
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        for layer in self.layers.iter_mut() {
+            layer.view.release_focus();
+        }
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.layers
+            .last()
+            .is_some_and(|top| top.view.has_pending_keys())
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        let top = self.layers.last()?;
+        let rect = top.view.focus_rect()?;
+        Some(Rect::new(top.rect.start() + rect.start(), rect.size()))
     }
 
     fn on_event(
         &mut self,
         state: &mut S,
-        event: E,
+        mut event: E,
     ) -> Option<Self::Message> {
-        self.layers.last_mut()?.on_event(state, event)
+        let top = self.layers.last_mut()?;
+        let rect = top.rect;
+        let popup = top.popup;
+
+        if let Some(me) = event.try_mouse() {
+            let pos = me.pos();
+
+            if !rect.contains(pos) {
+                if popup {
+                    if me.try_any_down().is_some() {
+                        self.pop_layer();
+                        state.set_need_redraw(true);
+                    }
+                    return None;
+                }
+
+                if top.modal {
+                    return None;
+                }
+
+                let (_, below) = self.layers.split_last_mut()?;
+
+                for layer in below.iter_mut().rev() {
+                    if layer.rect.contains(pos) {
+                        translate_mouse(&mut event, layer.rect);
+                        return layer.view.on_event(state, event);
+                    }
+                    if layer.modal {
+                        break;
+                    }
+                }
+
+                return None;
+            }
+
+            translate_mouse(&mut event, rect);
+        } else if popup && event.try_key().is_some_and(|ke| ke.try_esc()) {
+            self.pop_layer();
+            state.set_need_redraw(true);
+            return None;
+        }
+
+        let msg = top.view.on_event(state, event);
+
+        if popup && msg.is_some() {
+            self.pop_layer();
+            state.set_need_redraw(true);
+        }
+
+        msg
     }
 }
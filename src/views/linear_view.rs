@@ -1,20 +1,52 @@
 use crate::{
     event::{
         EventLike,
+        KeyEventLike,
         MouseEventLike,
     },
+    focus::{
+        cycle_focus,
+        walk_focus,
+        FocusDirection,
+    },
+    layout::{
+        solve,
+        Alignment,
+        Constraint,
+    },
     orientation::Orientation,
     printer::Printer,
+    rect::Rect,
     state::RedrawState,
     vec2::Vec2,
     view::View,
     view_wrappers::SizeCacher,
 };
 
+struct LinearChild<S, E, M> {
+    view:       SizeCacher<Box<dyn View<S, E, Message = M> + 'static>>,
+    constraint: Constraint,
+    pos:        Vec2,
+}
+
+impl<S, E, M> LinearChild<S, E, M> {
+    #[inline]
+    fn rect(&self) -> Rect {
+        Rect::new(self.pos, self.view.prev_size())
+    }
+}
+
 pub struct LinearView<S, E, M> {
-    children:    Vec<SizeCacher<Box<dyn View<S, E, Message = M> + 'static>>>,
+    children:    Vec<LinearChild<S, E, M>>,
     orientation: Orientation,
-    focus:       usize,
+    alignment:   Alignment,
+}
+
+impl<S, E, M> Default for LinearView<S, E, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<S, E, M> LinearView<S, E, M> {
@@ -22,7 +54,7 @@ impl<S, E, M> LinearView<S, E, M> {
         Self {
             children:    Vec::with_capacity(10),
             orientation: Orientation::Horizontal,
-            focus:       0,
+            alignment:   Alignment::Start,
         }
     }
 
@@ -45,12 +77,15 @@ impl<S, E, M> LinearView<S, E, M> {
         self
     }
 
+    /// Give focus to child at `focus`, nothing happens when it doesn't take focus
     #[inline]
     pub fn set_focus(
         &mut self,
         focus: usize,
     ) {
-        self.focus = focus
+        if focus < self.children.len() {
+            self.focus_child(focus);
+        }
     }
 
     #[inline]
@@ -70,21 +105,175 @@ impl<S, E, M> LinearView<S, E, M> {
         self.orientation = orientation;
     }
 
+    /// Set placement of children on the cross axis
     #[inline]
-    pub fn child(
+    pub fn alignment(
         mut self,
-        v: impl View<S, E, Message = M> + 'static,
+        alignment: Alignment,
     ) -> Self {
-        self.add_child(v);
+        self.set_alignment(alignment);
         self
     }
 
+    #[inline]
+    pub fn set_alignment(
+        &mut self,
+        alignment: Alignment,
+    ) {
+        self.alignment = alignment;
+    }
+
+    #[inline]
+    pub fn child(
+        self,
+        v: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.child_with(Constraint::Auto, v)
+    }
+
     #[inline]
     pub fn add_child(
         &mut self,
         v: impl View<S, E, Message = M> + 'static,
     ) {
-        self.children.push(SizeCacher::new(Box::new(v)));
+        self.add_child_with(Constraint::Auto, v);
+    }
+
+    #[inline]
+    pub fn child_with(
+        mut self,
+        constraint: Constraint,
+        v: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.add_child_with(constraint, v);
+        self
+    }
+
+    pub fn add_child_with(
+        &mut self,
+        constraint: Constraint,
+        v: impl View<S, E, Message = M> + 'static,
+    ) {
+        self.children.push(LinearChild {
+            view: SizeCacher::new(Box::new(v)),
+            constraint,
+            pos: Vec2::new(0, 0),
+        });
+    }
+
+    /// Change constraint of child at `index`
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds
+    #[inline]
+    pub fn set_constraint(
+        &mut self,
+        index: usize,
+        constraint: Constraint,
+    ) {
+        self.children[index].constraint = constraint;
+    }
+
+    fn desired_sizes(&self) -> Vec<Vec2> {
+        self.children
+            .iter()
+            .map(|c| c.view.desired_size())
+            .collect()
+    }
+
+    /// Lay out children with their `desired` sizes inside `size`
+    fn place_children(
+        &mut self,
+        size: Vec2,
+        desired: &[Vec2],
+    ) {
+        let (main, cross) = match self.orientation {
+            Orientation::Vertical => (size.y, size.x),
+            Orientation::Horizontal => (size.x, size.y),
+        };
+
+        let items: Vec<(Constraint, u16)> = self
+            .children
+            .iter()
+            .zip(desired.iter())
+            .map(|(c, d)| {
+                match self.orientation {
+                    Orientation::Vertical => (c.constraint, d.y),
+                    Orientation::Horizontal => (c.constraint, d.x),
+                }
+            })
+            .collect();
+
+        let mut offset = 0;
+
+        for ((child, d), len) in self
+            .children
+            .iter_mut()
+            .zip(desired)
+            .zip(solve(&items, main))
+        {
+            let (cross_pos, cross_len) = match self.orientation {
+                Orientation::Vertical => self.alignment.place(d.x, cross),
+                Orientation::Horizontal => self.alignment.place(d.y, cross),
+            };
+
+            let (pos, child_size) = match self.orientation {
+                Orientation::Vertical => (Vec2::new(cross_pos, offset), Vec2::new(cross_len, len)),
+                Orientation::Horizontal => {
+                    (Vec2::new(offset, cross_pos), Vec2::new(len, cross_len))
+                }
+            };
+
+            child.pos = pos;
+            child.view.layout(child_size);
+            offset += len;
+        }
+    }
+
+    #[inline]
+    fn focused_child(&self) -> Option<usize> {
+        self.children.iter().position(|c| c.view.has_focus())
+    }
+
+    /// Give focus to child which is clicked, focus is kept when it doesn't take focus
+    fn focus_child(
+        &mut self,
+        index: usize,
+    ) -> bool {
+        if self.children[index].view.has_focus()
+            || !self.children[index]
+                .view
+                .take_focus(FocusDirection::Forward)
+        {
+            return false;
+        }
+
+        for (i, child) in self.children.iter_mut().enumerate() {
+            if i != index {
+                child.view.release_focus();
+            }
+        }
+
+        true
+    }
+
+    /// Move focus to next child which takes it, wraps around at the end when `wrap` is set
+    fn step_focus(
+        &mut self,
+        direction: FocusDirection,
+        wrap: bool,
+    ) -> bool {
+        let current = self.focused_child();
+        let len = self.children.len();
+        let children = &mut self.children;
+        let take = |i: usize, direction| children[i].view.take_focus(direction);
+
+        if wrap {
+            cycle_focus(len, current, direction, take).is_some()
+        } else {
+            walk_focus(len, current, direction, take).is_some()
+        }
     }
 }
 
@@ -95,119 +284,120 @@ impl<S: RedrawState, E: EventLike, M> View<S, E> for LinearView<S, E, M> {
         &self,
         printer: &mut Printer,
     ) {
-        match self.orientation {
-            Orientation::Horizontal => {
-                let mut x = 0;
-                for child in self.children.iter() {
-                    printer.with_bound(printer.bound().add_start((x, 0)), |printer| {
-                        child.render(printer)
-                    });
-                    x += child.prev_size().x;
-                }
-            }
-            Orientation::Vertical => {
-                let mut y = 0;
-                for child in self.children.iter() {
-                    printer.with_bound(printer.bound().add_start((0, y)), |printer| {
-                        child.render(printer);
-                    });
-                    y += child.prev_size().y;
-                }
-            }
+        let start = printer.bound().start();
+
+        for child in self.children.iter() {
+            let rect = child.rect();
+            printer.with_bound(Rect::new(start + rect.start(), rect.size()), |printer| {
+                child.view.render(printer)
+            });
         }
     }
 
     fn desired_size(&self) -> Vec2 {
+        let sizes = self.children.iter().map(|c| {
+            let size = c.view.desired_size();
+            match self.orientation {
+                Orientation::Vertical => Vec2::new(size.x, c.constraint.desired(size.y)),
+                Orientation::Horizontal => Vec2::new(c.constraint.desired(size.x), size.y),
+            }
+        });
+
         match self.orientation {
             Orientation::Vertical => {
-                self.children
-                    .iter()
-                    .map(|c| c.desired_size())
-                    .fold(Vec2::new(0, 0), |acc, x| {
-                        Vec2::new(acc.x.max(x.x), acc.y + x.y)
-                    })
+                sizes.fold(Vec2::new(0, 0), |acc, x| {
+                    Vec2::new(acc.x.max(x.x), acc.y.saturating_add(x.y))
+                })
             }
             Orientation::Horizontal => {
-                self.children
-                    .iter()
-                    .map(|c| c.desired_size())
-                    .fold(Vec2::new(0, 0), |acc, x| {
-                        Vec2::new(acc.x + x.x, acc.y.max(x.y))
-                    })
+                sizes.fold(Vec2::new(0, 0), |acc, x| {
+                    Vec2::new(acc.x.saturating_add(x.x), acc.y.max(x.y))
+                })
             }
         }
     }
 
     fn layout(
         &mut self,
-        mut size: Vec2,
+        size: Vec2,
     ) {
-        for child in self.children.iter_mut() {
-            let child_size = child.desired_size();
-            child.layout(size.min(child_size));
+        let desired = self.desired_sizes();
+        self.place_children(size, &desired);
 
-            match self.orientation {
-                Orientation::Vertical => {
-                    size = size.saturating_sub_y(child_size.y);
-                }
-                Orientation::Horizontal => {
-                    size = size.saturating_sub_x(child_size.x);
-                }
-            }
+        // Children like wrapped text want a different size once they know their width
+        let laid_out = self.desired_sizes();
+        if laid_out != desired {
+            self.place_children(size, &laid_out);
+        }
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        self.step_focus(direction, false)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused_child().is_some()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        for child in self.children.iter_mut() {
+            child.view.release_focus();
         }
     }
 
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.focused_child()
+            .is_some_and(|i| self.children[i].view.has_pending_keys())
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        let child = &self.children[self.focused_child()?];
+        let rect = child.view.focus_rect()?;
+        Some(Rect::new(child.pos + rect.start(), rect.size()))
+    }
+
     fn on_event(
         &mut self,
         state: &mut S,
         mut event: E,
     ) -> Option<Self::Message> {
         if let Some(me) = event.try_mouse_mut() {
-            match self.orientation {
-                Orientation::Horizontal => {
-                    for (i, child) in self.children.iter_mut().enumerate() {
-                        let contains = !me.filter_map_pos(|pos| {
-                            let size = child.prev_size();
-                            if size.x >= pos.x {
-                                None
-                            } else {
-                                Some(pos.sub_x(size.x))
-                            }
-                        });
-                        if contains {
-                            self.focus = i;
-                            return child.on_event(state, event);
-                        }
-                    }
-                }
-                Orientation::Vertical => {
-                    for (i, child) in self.children.iter_mut().enumerate() {
-                        let contains = !me.filter_map_pos(|pos| {
-                            let size = child.prev_size();
-                            if size.y >= pos.y {
-                                None
-                            } else {
-                                Some(pos.sub_y(size.y))
-                            }
-                        });
-                        if contains {
-                            self.focus = i;
-                            return child.on_event(state, event);
-                        }
-                    }
-                }
+            let is_click = me.try_left_down().is_some();
+            let pos = me.pos();
+            let i = self.children.iter().position(|c| c.rect().contains(pos))?;
+            let start = self.children[i].pos;
+            me.map_pos(|pos| pos - start);
+
+            if is_click && self.focus_child(i) {
+                state.set_need_redraw(true);
             }
 
-            None
-        } else if let Some(_) = event.try_key() {
-            if let Some(focus) = self.children.get_mut(self.focus) {
-                focus.on_event(state, event)
-            } else if !self.children.is_empty() {
-                self.focus = self.children.len() - 1;
-                self.children.last_mut()?.on_event(state, event)
+            self.children[i].view.on_event(state, event)
+        } else if let Some(ke) = event.try_key() {
+            let direction = if ke.try_tab() {
+                Some(FocusDirection::Forward)
+            } else if ke.try_back_tab() {
+                Some(FocusDirection::Backward)
             } else {
                 None
+            };
+
+            if let Some(direction) = direction {
+                if self.step_focus(direction, true) {
+                    state.set_need_redraw(true);
+                    return None;
+                }
             }
+
+            let i = self.focused_child()?;
+            self.children[i].view.on_event(state, event)
         } else {
             None
         }
@@ -0,0 +1,281 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyEventLike,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
+    printer::Printer,
+    rect::Rect,
+    state::RedrawState,
+    style::Style,
+    vec2::Vec2,
+    view::View,
+};
+use std::marker::PhantomData;
+
+/// Items of [`ListView`]
+///
+/// Only items in view are rendered so `len` can be huge.
+pub trait ListSource {
+    fn len(&self) -> usize;
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Render item at `index` on a single row `printer`
+    fn render_item(
+        &self,
+        index: usize,
+        printer: &mut Printer,
+    );
+
+    /// Width asked for by [`ListView::desired_size`](View::desired_size)
+    ///
+    /// `0` by default as measuring every item defeats the point, put the list in a container
+    /// which stretches it or override this.
+    #[inline]
+    fn width(&self) -> u16 {
+        0
+    }
+}
+
+impl<T: AsRef<str>> ListSource for Vec<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    #[inline]
+    fn render_item(
+        &self,
+        index: usize,
+        printer: &mut Printer,
+    ) {
+        printer.print((0, 0), self[index].as_ref());
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ListViewMessage {
+    Select,
+    IndexChanged,
+}
+
+/// Selectable list which renders only visible items of a [`ListSource`]
+pub struct ListView<S, E, D> {
+    source:   D,
+    selected: usize,
+    /// Index of first visible item
+    scroll:   usize,
+    size:     Vec2,
+    focused:  bool,
+    _marker:  PhantomData<(S, E)>,
+}
+
+impl<S: RedrawState, E, D: ListSource> ListView<S, E, D> {
+    pub fn new(source: D) -> Self {
+        Self {
+            source,
+            selected: 0,
+            scroll: 0,
+            size: Vec2::new(0, 0),
+            focused: false,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn source(&self) -> &D {
+        &self.source
+    }
+
+    /// Change items with `f` and redraw
+    ///
+    /// Selection is kept at the same index and moved into range when items are removed.
+    pub fn update(
+        &mut self,
+        state: &mut S,
+        f: impl FnOnce(&mut D),
+    ) {
+        f(&mut self.source);
+
+        self.selected = self.selected.min(self.source.len().saturating_sub(1));
+        self.scroll = self.scroll.min(self.max_scroll());
+        self.scroll_to_selected();
+        state.set_need_redraw(true);
+    }
+
+    #[inline]
+    pub fn selected(&self) -> Option<usize> {
+        if self.source.is_empty() {
+            None
+        } else {
+            Some(self.selected)
+        }
+    }
+
+    /// Index of first visible item
+    #[inline]
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Select item at `index` and scroll to it
+    pub fn select(
+        &mut self,
+        state: &mut S,
+        index: usize,
+    ) -> Option<ListViewMessage> {
+        let index = index.min(self.source.len().saturating_sub(1));
+
+        if index == self.selected {
+            None
+        } else {
+            self.selected = index;
+            self.scroll_to_selected();
+            state.set_need_redraw(true);
+            Some(ListViewMessage::IndexChanged)
+        }
+    }
+
+    /// Number of visible rows
+    #[inline]
+    fn page(&self) -> usize {
+        self.size.y.max(1) as usize
+    }
+
+    #[inline]
+    fn max_scroll(&self) -> usize {
+        self.source.len().saturating_sub(self.page())
+    }
+
+    fn scroll_to_selected(&mut self) {
+        let page = self.page();
+
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + page {
+            self.scroll = self.selected + 1 - page;
+        }
+    }
+}
+
+impl<S: RedrawState, E: EventLike, D: ListSource> View<S, E> for ListView<S, E, D> {
+    type Message = ListViewMessage;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let start = printer.bound().start();
+        let width = self.size.x;
+        let highlight = if self.focused {
+            Style::highlight()
+        } else {
+            Style::highlight_inactive()
+        };
+
+        let end = self.source.len().min(self.scroll + self.size.y as usize);
+
+        printer.with_style(Style::view(), |printer| {
+            for (y, index) in (0..).zip(self.scroll..end) {
+                let bound = Rect::new(start.add_y(y), (width, 1));
+
+                printer.with_bound(bound, |printer| {
+                    if index == self.selected {
+                        printer.with_style(highlight, |printer| {
+                            printer.print((0, 0), &" ".repeat(width as usize));
+                            self.source.render_item(index, printer);
+                        });
+                    } else {
+                        self.source.render_item(index, printer);
+                    }
+                });
+            }
+        });
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+        self.scroll = self.scroll.min(self.max_scroll());
+        self.scroll_to_selected();
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        let height = self.source.len().min(u16::MAX as usize) as u16;
+        Vec2::new(self.source.width(), height)
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        e: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = e.try_mouse() {
+            if let Some(pos) = me.try_left_down() {
+                let index = self.scroll + pos.y as usize;
+
+                if index >= self.source.len() {
+                    return None;
+                }
+
+                return self.select(state, index).or(Some(ListViewMessage::Select));
+            } else if me.try_scroll_up().is_some() {
+                return self.select(state, self.selected.saturating_sub(1));
+            } else if me.try_scroll_down().is_some() {
+                return self.select(state, self.selected + 1);
+            }
+
+            return None;
+        }
+
+        let ke = e.try_key()?;
+
+        if ke.try_enter() {
+            if self.source.is_empty() {
+                None
+            } else {
+                Some(ListViewMessage::Select)
+            }
+        } else if ke.try_up() {
+            self.select(state, self.selected.saturating_sub(1))
+        } else if ke.try_down() {
+            self.select(state, self.selected + 1)
+        } else if ke.try_home() {
+            self.select(state, 0)
+        } else if ke.try_end() {
+            self.select(state, self.source.len())
+        } else if ke.try_page_up() {
+            self.select(state, self.selected.saturating_sub(self.page()))
+        } else if ke.try_page_down() {
+            self.select(state, self.selected + self.page())
+        } else {
+            None
+        }
+    }
+}
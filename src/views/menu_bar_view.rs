@@ -0,0 +1,811 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyCode,
+        KeyEvent,
+        KeyEventLike,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
+    printer::Printer,
+    rect::Rect,
+    state::RedrawState,
+    style::Style,
+    vec2::Vec2,
+    view::View,
+    view_wrappers::SizeCacher,
+    views::LayeredView,
+};
+use std::marker::PhantomData;
+use unicode_width::UnicodeWidthStr;
+
+const SUBMENU_HINT: &str = "▸";
+
+#[derive(Clone)]
+enum MenuItemKind<M> {
+    Action(M),
+    Submenu(Menu<M>),
+    Separator,
+}
+
+#[derive(Clone)]
+pub struct MenuItem<M> {
+    kind:     MenuItemKind<M>,
+    label:    String,
+    shortcut: String,
+    enabled:  bool,
+}
+
+impl<M> MenuItem<M> {
+    pub fn action(
+        label: impl Into<String>,
+        msg: M,
+    ) -> Self {
+        Self::with_kind(label.into(), MenuItemKind::Action(msg))
+    }
+
+    pub fn submenu(
+        label: impl Into<String>,
+        menu: Menu<M>,
+    ) -> Self {
+        Self::with_kind(label.into(), MenuItemKind::Submenu(menu))
+    }
+
+    pub fn separator() -> Self {
+        Self::with_kind(String::new(), MenuItemKind::Separator)
+    }
+
+    fn with_kind(
+        label: String,
+        kind: MenuItemKind<M>,
+    ) -> Self {
+        Self {
+            kind,
+            label,
+            shortcut: String::new(),
+            enabled: true,
+        }
+    }
+
+    /// Key hint shown at the right side, it is only a text
+    #[inline]
+    pub fn shortcut(
+        mut self,
+        shortcut: impl Into<String>,
+    ) -> Self {
+        self.shortcut = shortcut.into();
+        self
+    }
+
+    #[inline]
+    pub fn enabled(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    #[inline]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Message of action item
+    #[inline]
+    pub fn message(&self) -> Option<&M> {
+        match &self.kind {
+            MenuItemKind::Action(msg) => Some(msg),
+            _ => None,
+        }
+    }
+
+    /// Menu of submenu item
+    #[inline]
+    pub fn menu(&self) -> Option<&Menu<M>> {
+        match &self.kind {
+            MenuItemKind::Submenu(menu) => Some(menu),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, MenuItemKind::Separator)
+    }
+
+    #[inline]
+    pub(crate) fn selectable(&self) -> bool {
+        self.enabled && !self.is_separator()
+    }
+
+    #[inline]
+    fn hint(&self) -> &str {
+        match self.kind {
+            MenuItemKind::Submenu(_) => SUBMENU_HINT,
+            _ => &self.shortcut,
+        }
+    }
+}
+
+/// List of items shown in a drop-down
+#[derive(Clone)]
+pub struct Menu<M> {
+    items: Vec<MenuItem<M>>,
+}
+
+impl<M> Default for Menu<M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Menu<M> {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::with_capacity(10),
+        }
+    }
+
+    #[inline]
+    pub fn item(
+        mut self,
+        item: MenuItem<M>,
+    ) -> Self {
+        self.add_item(item);
+        self
+    }
+
+    #[inline]
+    pub fn add_item(
+        &mut self,
+        item: MenuItem<M>,
+    ) {
+        self.items.push(item);
+    }
+
+    #[inline]
+    pub fn action(
+        self,
+        label: impl Into<String>,
+        msg: M,
+    ) -> Self {
+        self.item(MenuItem::action(label, msg))
+    }
+
+    #[inline]
+    pub fn submenu(
+        self,
+        label: impl Into<String>,
+        menu: Menu<M>,
+    ) -> Self {
+        self.item(MenuItem::submenu(label, menu))
+    }
+
+    #[inline]
+    pub fn separator(self) -> Self {
+        self.item(MenuItem::separator())
+    }
+
+    #[inline]
+    pub fn items(&self) -> &[MenuItem<M>] {
+        &self.items
+    }
+
+    /// Size of drop-down including outline
+    pub fn size(&self) -> Vec2 {
+        let label = self.items.iter().map(|item| item.label.width()).max();
+        let hint = self.items.iter().map(|item| item.hint().width()).max();
+        let label = label.unwrap_or(0) as u16;
+        let hint = hint.unwrap_or(0) as u16;
+        let hint = if hint > 0 { hint + 2 } else { 0 };
+
+        // Padding at both sides
+        Vec2::new(label + hint + 4, self.items.len() as u16 + 2)
+    }
+
+    /// Next selectable item after `from`, wraps around
+    pub(crate) fn next_selectable(
+        &self,
+        from: usize,
+        forward: bool,
+    ) -> Option<usize> {
+        let len = self.items.len();
+
+        (1..=len)
+            .map(|step| {
+                if forward {
+                    (from + step) % len
+                } else {
+                    (from + len - step % len) % len
+                }
+            })
+            .find(|&i| self.items[i].selectable())
+    }
+
+    #[inline]
+    pub(crate) fn first_selectable(&self) -> usize {
+        self.items
+            .iter()
+            .position(MenuItem::selectable)
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn render(
+        &self,
+        printer: &mut Printer,
+        highlight: Option<usize>,
+    ) {
+        let size = printer.bound().size();
+        let blank = " ".repeat(size.x as usize);
+
+        printer.with_style(Style::view(), |printer| {
+            for y in 0..size.y {
+                printer.print((0, y), &blank);
+            }
+        });
+        printer.with_style(Style::outline(), |printer| printer.print_rect());
+
+        for (i, item) in self.items.iter().enumerate() {
+            let y = i as u16 + 1;
+
+            if item.is_separator() {
+                printer.with_style(Style::outline(), |printer| {
+                    printer.print((0, y), "├");
+                    printer.print_horizontal_line_at((1, y), size.x.saturating_sub(2) as usize);
+                    printer.print((size.x.saturating_sub(1), y), "┤");
+                });
+                continue;
+            }
+
+            let style = if !item.enabled {
+                Style::secondary()
+            } else if highlight == Some(i) {
+                Style::highlight()
+            } else {
+                Style::view()
+            };
+
+            printer.with_style(style, |printer| {
+                let inner = size.x.saturating_sub(2);
+                printer.print((1, y), &" ".repeat(inner as usize));
+                printer.print((2, y), &item.label);
+
+                let hint = item.hint();
+                let x = (inner + 1).saturating_sub(hint.width() as u16 + 1);
+                printer.print((x, y), hint);
+            });
+        }
+    }
+}
+
+/// Message of [`DropDown`], any of them closes its layer
+enum DropDownMessage<M> {
+    Action(M),
+    /// Open next or previous menu of the bar
+    Switch(bool),
+    Close,
+}
+
+/// Open menu of [`MenuBarView`] with its submenus cascading to the right
+struct DropDown<S, E, M> {
+    menu:    Menu<M>,
+    /// Highlighted item of each open level
+    path:    Vec<usize>,
+    size:    Vec2,
+    _marker: PhantomData<(S, E)>,
+}
+
+impl<S, E, M> DropDown<S, E, M> {
+    fn new(menu: Menu<M>) -> Self {
+        let mut path = Vec::with_capacity(3);
+        path.push(menu.first_selectable());
+
+        Self {
+            menu,
+            path,
+            size: Vec2::new(0, 0),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Menu at `level` of open submenus
+    fn menu_at(
+        &self,
+        level: usize,
+    ) -> Option<&Menu<M>> {
+        let mut menu = &self.menu;
+
+        for &i in &self.path[..level] {
+            match &menu.items.get(i)?.kind {
+                MenuItemKind::Submenu(sub) => menu = sub,
+                _ => return None,
+            }
+        }
+
+        Some(menu)
+    }
+
+    /// Bounds of open levels, clamped into `size`
+    fn boxes(
+        &self,
+        size: Vec2,
+    ) -> Vec<Rect> {
+        let mut boxes: Vec<Rect> = Vec::with_capacity(self.path.len());
+
+        for level in 0..self.path.len() {
+            let menu_size = match self.menu_at(level) {
+                Some(menu) => menu.size(),
+                None => break,
+            };
+            let pos = match boxes.last() {
+                Some(prev) => {
+                    Vec2::new(prev.x() + prev.w(), prev.y() + self.path[level - 1] as u16)
+                }
+                None => Vec2::new(0, 0),
+            };
+            let pos = Vec2::new(
+                pos.x.min(size.x.saturating_sub(menu_size.x)),
+                pos.y.min(size.y.saturating_sub(menu_size.y)),
+            );
+
+            boxes.push(Rect::new(pos, menu_size));
+        }
+
+        boxes
+    }
+
+    /// Open submenu or return message of highlighted item at deepest level
+    fn activate(&mut self) -> Option<DropDownMessage<M>>
+    where
+        M: Clone,
+    {
+        let level = self.path.len() - 1;
+        let item = self.menu_at(level)?.items.get(self.path[level])?;
+
+        if !item.enabled {
+            return None;
+        }
+
+        match &item.kind {
+            MenuItemKind::Action(msg) => Some(DropDownMessage::Action(msg.clone())),
+            MenuItemKind::Submenu(sub) => {
+                let first = sub.first_selectable();
+                self.path.push(first);
+                None
+            }
+            MenuItemKind::Separator => None,
+        }
+    }
+
+    fn move_highlight(
+        &mut self,
+        forward: bool,
+    ) {
+        let level = self.path.len() - 1;
+
+        if let Some(next) = self
+            .menu_at(level)
+            .and_then(|menu| menu.next_selectable(self.path[level], forward))
+        {
+            self.path[level] = next;
+        }
+    }
+
+    fn on_key(
+        &mut self,
+        ke: &impl KeyEventLike,
+    ) -> Option<DropDownMessage<M>>
+    where
+        M: Clone,
+    {
+        if ke.try_up() {
+            self.move_highlight(false);
+        } else if ke.try_down() {
+            self.move_highlight(true);
+        } else if ke.try_enter() {
+            return self.activate();
+        } else if ke.try_right() {
+            let level = self.path.len() - 1;
+            let is_submenu = self
+                .menu_at(level)
+                .and_then(|menu| menu.items.get(self.path[level]))
+                .is_some_and(|item| item.enabled && matches!(item.kind, MenuItemKind::Submenu(_)));
+
+            if !is_submenu {
+                return Some(DropDownMessage::Switch(true));
+            }
+
+            self.activate();
+        } else if ke.try_left() {
+            if self.path.len() == 1 {
+                return Some(DropDownMessage::Switch(false));
+            }
+
+            self.path.pop();
+        }
+
+        None
+    }
+
+    fn on_click(
+        &mut self,
+        pos: Vec2,
+    ) -> Option<DropDownMessage<M>>
+    where
+        M: Clone,
+    {
+        let boxes = self.boxes(self.size);
+
+        // Deeper levels are on top
+        for (level, rect) in boxes.iter().enumerate().rev() {
+            if !rect.contains(pos) {
+                continue;
+            }
+
+            let row = (pos.y - rect.y()) as usize;
+            let index = match row.checked_sub(1) {
+                Some(index) if index < rect.h() as usize - 2 => index,
+                _ => return None,
+            };
+
+            if !self.menu_at(level)?.items[index].selectable() {
+                return None;
+            }
+
+            self.path.truncate(level + 1);
+            self.path[level] = index;
+            return self.activate();
+        }
+
+        Some(DropDownMessage::Close)
+    }
+}
+
+impl<S, E, M> View<S, E> for DropDown<S, E, M>
+where
+    E: EventLike,
+    M: Clone,
+{
+    type Message = DropDownMessage<M>;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let start = printer.bound().start();
+
+        for (level, rect) in self.boxes(self.size).into_iter().enumerate() {
+            if let Some(menu) = self.menu_at(level) {
+                printer.with_bound(Rect::new(start + rect.start(), rect.size()), |printer| {
+                    menu.render(printer, Some(self.path[level]));
+                });
+            }
+        }
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        self.boxes(Vec2::new(u16::MAX, u16::MAX))
+            .into_iter()
+            .fold(Vec2::new(0, 0), |acc, rect| {
+                let end = rect.end();
+                Vec2::new(acc.x.max(end.x), acc.y.max(end.y))
+            })
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut S,
+        e: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = e.try_mouse() {
+            self.on_click(me.try_left_down()?)
+        } else {
+            self.on_key(e.try_key()?)
+        }
+    }
+}
+
+/// Content with a menu bar on the top row
+///
+/// An open menu is a popup layer over the content, it captures all events until `Esc`, a
+/// selection or a click outside of it.
+pub struct MenuBarView<S, E, M, C> {
+    menus:    Vec<(String, Menu<M>)>,
+    content:  SizeCacher<C>,
+    /// Drop-down of open menu
+    layers:   LayeredView<S, E, DropDownMessage<M>>,
+    /// Index of open top-level menu
+    open:     Option<usize>,
+    size:     Vec2,
+    open_key: KeyEvent,
+}
+
+impl<S, E, M, C> MenuBarView<S, E, M, C> {
+    pub fn new(content: C) -> Self {
+        Self {
+            menus:    Vec::with_capacity(5),
+            content:  SizeCacher::new(content),
+            layers:   LayeredView::new(),
+            open:     None,
+            size:     Vec2::new(0, 0),
+            open_key: KeyCode::F(10).into(),
+        }
+    }
+
+    #[inline]
+    pub fn menu(
+        mut self,
+        title: impl Into<String>,
+        menu: Menu<M>,
+    ) -> Self {
+        self.add_menu(title, menu);
+        self
+    }
+
+    #[inline]
+    pub fn add_menu(
+        &mut self,
+        title: impl Into<String>,
+        menu: Menu<M>,
+    ) {
+        self.menus.push((title.into(), menu));
+    }
+
+    /// Key which opens the first menu, `F10` by default
+    #[inline]
+    pub fn open_key(
+        mut self,
+        key: KeyEvent,
+    ) -> Self {
+        self.set_open_key(key);
+        self
+    }
+
+    #[inline]
+    pub fn set_open_key(
+        &mut self,
+        key: KeyEvent,
+    ) {
+        self.open_key = key;
+    }
+
+    #[inline]
+    pub fn content(&self) -> &C {
+        self.content.inner()
+    }
+
+    #[inline]
+    pub fn content_mut(&mut self) -> &mut C {
+        self.content.inner_mut()
+    }
+
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    #[inline]
+    pub fn close(&mut self) {
+        self.open = None;
+        self.layers = LayeredView::new();
+    }
+
+    fn title_x(
+        &self,
+        index: usize,
+    ) -> u16 {
+        self.menus[..index]
+            .iter()
+            .map(|(title, _)| title.width() as u16 + 2)
+            .sum()
+    }
+
+    fn title_at(
+        &self,
+        x: u16,
+    ) -> Option<usize> {
+        let mut start = 0;
+
+        for (i, (title, _)) in self.menus.iter().enumerate() {
+            start += title.width() as u16 + 2;
+            if x < start {
+                return Some(i);
+            }
+        }
+
+        None
+    }
+}
+
+impl<S, E, M, C> MenuBarView<S, E, M, C>
+where
+    S: RedrawState + 'static,
+    E: EventLike + 'static,
+    M: Clone + 'static,
+{
+    /// Open top-level menu at `index` as a popup layer under its title
+    pub fn open(
+        &mut self,
+        index: usize,
+    ) {
+        if let Some((_, menu)) = self.menus.get(index) {
+            let drop_down = DropDown::new(menu.clone());
+
+            self.close();
+            self.layers.add_popup((self.title_x(index), 0), drop_down);
+            self.layers.layout(self.size.saturating_sub_y(1));
+            self.open = Some(index);
+        }
+    }
+
+    /// Open menu next to the one at `from`
+    fn switch_menu(
+        &mut self,
+        from: usize,
+        forward: bool,
+    ) {
+        let len = self.menus.len();
+        let next = if forward {
+            (from + 1) % len
+        } else {
+            (from + len - 1) % len
+        };
+
+        self.open(next);
+    }
+}
+
+impl<S, E, M, C> View<S, E> for MenuBarView<S, E, M, C>
+where
+    S: RedrawState + 'static,
+    E: EventLike + 'static,
+    M: Clone + 'static,
+    C: View<S, E, Message = M>,
+{
+    type Message = M;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let bound = printer.bound();
+        let start = bound.start();
+
+        printer.with_bound(
+            Rect::new(start.add_y(1), bound.size().saturating_sub_y(1)),
+            |printer| {
+                self.content.render(printer);
+                self.layers.render(printer);
+            },
+        );
+
+        printer.with_style(Style::view(), |printer| {
+            printer.print((0, 0), &" ".repeat(bound.w() as usize));
+
+            let mut x = 0;
+            for (i, (title, _)) in self.menus.iter().enumerate() {
+                let text = format!(" {} ", title);
+
+                if self.open == Some(i) {
+                    printer.with_style(Style::highlight(), |printer| printer.print((x, 0), &text));
+                } else {
+                    printer.print((x, 0), &text);
+                }
+
+                x += text.width() as u16;
+            }
+        });
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+        self.content.layout(size.saturating_sub_y(1));
+        self.layers.layout(size.saturating_sub_y(1));
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        let content = self.content.desired_size();
+        let bar = self.title_x(self.menus.len());
+
+        Vec2::new(content.x.max(bar), content.y.saturating_add(1))
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        self.content.take_focus(direction)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.content.has_focus()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.content.release_focus();
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.content.has_pending_keys()
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        let rect = self.content.focus_rect()?;
+        Some(Rect::new(rect.start().add_y(1), rect.size()))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        mut event: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = event.try_mouse_mut() {
+            if let Some(pos) = me.try_left_down() {
+                if pos.y == 0 {
+                    match self.title_at(pos.x) {
+                        Some(index) if self.open != Some(index) => self.open(index),
+                        _ if self.is_open() => self.close(),
+                        _ => return None,
+                    }
+                    state.set_need_redraw(true);
+                    return None;
+                }
+            }
+
+            if !me.filter_map_pos(|pos| pos.y.checked_sub(1).map(|y| Vec2::new(pos.x, y))) {
+                return None;
+            }
+        } else if let Some(ke) = event.try_key() {
+            if !self.is_open() && ke.key() == self.open_key && !self.menus.is_empty() {
+                self.open(0);
+                state.set_need_redraw(true);
+                return None;
+            }
+        }
+
+        let open = match self.open {
+            Some(open) => open,
+            None => return self.content.on_event(state, event),
+        };
+
+        state.set_need_redraw(true);
+
+        let msg = self.layers.on_event(state, event);
+        // Submenus change size of the layer
+        self.layers.layout(self.size.saturating_sub_y(1));
+
+        if self.layers.is_empty() {
+            self.open = None;
+        }
+
+        match msg? {
+            DropDownMessage::Action(msg) => Some(msg),
+            DropDownMessage::Switch(forward) => {
+                self.switch_menu(open, forward);
+                None
+            }
+            DropDownMessage::Close => None,
+        }
+    }
+}
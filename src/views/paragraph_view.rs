@@ -1,35 +1,111 @@
 use crate::{
+    ansi::AnsiParser,
+    layout::TextAlign,
+    markup::{
+        self,
+        MarkupError,
+    },
     never::Never,
     printer::Printer,
     style::Style,
+    styled_text::StyledText,
+    util::wrap_line,
     vec2::Vec2,
     view::View,
 };
-use std::marker::PhantomData;
+use std::{
+    marker::PhantomData,
+    ops::Range,
+};
 use unicode_width::UnicodeWidthStr;
 
+/// Part of a line which is rendered in a single row
+#[derive(Clone, Debug)]
+struct Row {
+    line:  usize,
+    range: Range<usize>,
+    /// Last row of the line
+    last:  bool,
+}
+
 pub struct ParagraphView<S, E> {
-    lines:   Vec<String>,
-    width:   usize,
-    _marker: PhantomData<(S, E)>,
+    lines:      Vec<StyledText>,
+    width:      usize,
+    rows:       Vec<Row>,
+    /// Width used for wrapping, `0` when not laid out yet
+    wrap_width: u16,
+    wrap:       bool,
+    align:      TextAlign,
+    /// Keeps SGR style between [`append_ansi`](Self::append_ansi) calls
+    ansi:       AnsiParser,
+    _marker:    PhantomData<(S, E)>,
+}
+
+impl<S, E> Default for ParagraphView<S, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<S, E> ParagraphView<S, E> {
     pub fn new() -> Self {
         let mut lines = Vec::with_capacity(10);
-        lines.push(String::with_capacity(100));
+        lines.push(StyledText::new());
         Self {
             lines,
             width: 0,
+            rows: Vec::new(),
+            wrap_width: 0,
+            wrap: true,
+            align: TextAlign::Left,
+            ansi: AnsiParser::new(),
             _marker: PhantomData,
         }
     }
 
+    /// Wrap lines at the laid out width, otherwise long lines are truncated
+    #[inline]
+    pub fn wrap(
+        mut self,
+        wrap: bool,
+    ) -> Self {
+        self.set_wrap(wrap);
+        self
+    }
+
+    #[inline]
+    pub fn set_wrap(
+        &mut self,
+        wrap: bool,
+    ) {
+        self.wrap = wrap;
+        self.reflow_from(0);
+    }
+
+    #[inline]
+    pub fn align(
+        mut self,
+        align: TextAlign,
+    ) -> Self {
+        self.set_align(align);
+        self
+    }
+
+    #[inline]
+    pub fn set_align(
+        &mut self,
+        align: TextAlign,
+    ) {
+        self.align = align;
+    }
+
     pub fn append(
         &mut self,
-        text: &str,
+        text: impl Into<StyledText>,
     ) {
-        let mut lines = text.split('\n');
+        let text = text.into();
+        let mut lines = text.split_lines().into_iter();
 
         let first_line = match lines.next() {
             Some(first) => first,
@@ -38,23 +114,48 @@ impl<S, E> ParagraphView<S, E> {
             }
         };
 
-        let last_line = self.lines.last_mut().unwrap();
-        last_line.push_str(first_line);
+        let last = self.lines.len() - 1;
+        let last_line = &mut self.lines[last];
+        last_line.append_text(&first_line);
 
         self.width = self.width.max(last_line.width());
+        self.reflow_from(last);
 
         for line in lines {
             self.push_line(line);
         }
     }
 
+    /// Append text written in [`markup`](crate::markup), nothing is appended on error
+    pub fn append_markup(
+        &mut self,
+        text: &str,
+    ) -> Result<(), MarkupError> {
+        self.append(markup::parse(text)?);
+        Ok(())
+    }
+
+    /// Append output of a terminal program, SGR colors are kept and other escape sequences are
+    /// removed
+    ///
+    /// Style of the last SGR sequence is carried to the next call so output can be fed line by
+    /// line.
+    pub fn append_ansi(
+        &mut self,
+        text: &str,
+    ) {
+        let text = self.ansi.parse(text);
+        self.append(text);
+    }
+
     pub fn new_line(&mut self) {
-        self.lines.push(String::with_capacity(50));
+        self.lines.push(StyledText::new());
+        self.reflow_from(self.lines.len() - 1);
     }
 
     pub fn append_line(
         &mut self,
-        text: &str,
+        text: impl Into<StyledText>,
     ) {
         self.append(text);
         self.new_line();
@@ -62,11 +163,92 @@ impl<S, E> ParagraphView<S, E> {
 
     pub fn push_line(
         &mut self,
-        line: impl Into<String>,
+        line: impl Into<StyledText>,
     ) {
         let line = line.into();
         self.width = self.width.max(line.width());
         self.lines.push(line);
+        self.reflow_from(self.lines.len() - 1);
+    }
+
+    /// Number of rows when laid out with `width`
+    pub fn height_for_width(
+        &self,
+        width: u16,
+    ) -> usize {
+        if !self.wrap || width == 0 {
+            return self.lines.len();
+        }
+
+        if width == self.wrap_width {
+            return self.rows.len();
+        }
+
+        self.lines
+            .iter()
+            .map(|line| wrap_line(line.source(), width as usize).len())
+            .sum()
+    }
+
+    /// Recompute rows of lines from `line`
+    fn reflow_from(
+        &mut self,
+        line: usize,
+    ) {
+        let keep = self
+            .rows
+            .iter()
+            .position(|row| row.line >= line)
+            .unwrap_or(self.rows.len());
+        self.rows.truncate(keep);
+
+        for (i, text) in self.lines.iter().enumerate().skip(line) {
+            if self.wrap && self.wrap_width > 0 {
+                let ranges = wrap_line(text.source(), self.wrap_width as usize);
+                let count = ranges.len();
+                self.rows
+                    .extend(ranges.into_iter().enumerate().map(|(n, range)| {
+                        Row {
+                            line: i,
+                            range,
+                            last: n + 1 == count,
+                        }
+                    }));
+            } else {
+                self.rows.push(Row {
+                    line:  i,
+                    range: 0..text.len(),
+                    last:  true,
+                });
+            }
+        }
+    }
+
+    fn render_justified(
+        &self,
+        printer: &mut Printer,
+        y: u16,
+        row: &Row,
+    ) {
+        let line = &self.lines[row.line];
+        let text = &line.source()[row.range.clone()];
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        if words.len() < 2 {
+            printer.print_spans((0, y), line.slice(row.range.clone()));
+            return;
+        }
+
+        let gaps = words.len() - 1;
+        let words_width: usize = words.iter().map(|w| w.width()).sum();
+        let space = (self.wrap_width as usize).saturating_sub(words_width);
+        let mut x = 0;
+
+        for (i, word) in words.iter().enumerate() {
+            let start = row.range.start + (word.as_ptr() as usize - text.as_ptr() as usize);
+            printer.print_spans((x as u16, y), line.slice(start..start + word.len()));
+            x += word.width() + space / gaps + (i < space % gaps) as usize;
+        }
     }
 }
 
@@ -77,21 +259,47 @@ impl<S, E> View<S, E> for ParagraphView<S, E> {
         &self,
         printer: &mut Printer,
     ) {
+        let width = self.wrap_width as usize;
+        let visible = printer.visible();
+        let start = visible.y() as usize;
+        let end = (visible.y() + visible.h()) as usize;
+
         printer.with_style(Style::view(), |printer| {
-            for (y, line) in self.lines.iter().enumerate() {
-                printer.print((0, y as u16), line);
+            for (y, row) in self.rows.iter().enumerate().take(end).skip(start) {
+                let y = y as u16;
+                let line = &self.lines[row.line];
+                let spans = line.slice(row.range.clone());
+                let rest = width.saturating_sub(line.source()[row.range.clone()].width()) as u16;
+
+                match self.align {
+                    TextAlign::Left => printer.print_spans((0, y), spans),
+                    TextAlign::Center => printer.print_spans((rest / 2, y), spans),
+                    TextAlign::Right => printer.print_spans((rest, y), spans),
+                    TextAlign::Justify if !row.last => self.render_justified(printer, y, row),
+                    TextAlign::Justify => printer.print_spans((0, y), spans),
+                }
             }
         });
     }
 
     fn layout(
         &mut self,
-        _size: Vec2,
+        size: Vec2,
     ) {
+        if self.wrap_width != size.x || self.rows.is_empty() {
+            self.wrap_width = size.x;
+            self.reflow_from(0);
+        }
     }
 
+    /// Width is the widest line, height is number of rows at the last laid out width
+    ///
+    /// Containers which lay out again after reading the height get the wrapped height.
     fn desired_size(&self) -> Vec2 {
-        Vec2::new(self.width as u16, self.lines.len() as u16)
+        let width = self.width.min(u16::MAX as usize) as u16;
+        let height = self.height_for_width(self.wrap_width);
+
+        Vec2::new(width, height.min(u16::MAX as usize) as u16)
     }
 
     fn on_event(
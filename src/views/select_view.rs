@@ -4,6 +4,7 @@ use crate::{
         KeyEventLike,
         MouseEventLike,
     },
+    focus::FocusDirection,
     printer::Printer,
     state::RedrawState,
     style::Style,
@@ -17,15 +18,26 @@ pub struct SelectView<S, E, T> {
     btns:     Vec<(String, T)>,
     selected: usize,
     width:    u16,
+    height:   u16,
+    focused:  bool,
     _marker:  PhantomData<(S, E)>,
 }
 
+impl<S: RedrawState, E, T> Default for SelectView<S, E, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<S: RedrawState, E, T> SelectView<S, E, T> {
     pub fn new() -> Self {
         Self {
             btns:     Vec::new(),
             selected: 0,
             width:    0,
+            height:   0,
+            focused:  false,
             _marker:  PhantomData,
         }
     }
@@ -43,6 +55,8 @@ impl<S: RedrawState, E, T> SelectView<S, E, T> {
             btns,
             selected: 0,
             width,
+            height: 0,
+            focused: false,
             _marker: PhantomData,
         }
     }
@@ -75,6 +89,27 @@ impl<S: RedrawState, E, T> SelectView<S, E, T> {
         }
     }
 
+    pub fn select(
+        &mut self,
+        state: &mut S,
+        index: usize,
+    ) -> Option<SelectViewMessage> {
+        let index = index.min(self.btns.len().saturating_sub(1));
+
+        if index == self.selected {
+            None
+        } else {
+            self.selected = index;
+            state.set_need_redraw(true);
+            Some(SelectViewMessage::IndexChanged)
+        }
+    }
+
+    #[inline]
+    fn page(&self) -> usize {
+        self.height.max(1) as usize
+    }
+
     pub fn selected_val(&self) -> &T {
         &self.btns[self.selected].1
     }
@@ -97,10 +132,16 @@ impl<S: RedrawState, E: EventLike, T> View<S, E> for SelectView<S, E, T> {
         &self,
         printer: &mut Printer,
     ) {
+        let highlight = if self.focused {
+            Style::highlight()
+        } else {
+            Style::highlight_inactive()
+        };
+
         printer.with_style(Style::view(), |printer| {
             for (i, (text, _)) in self.btns.iter().enumerate() {
                 if i == self.selected {
-                    printer.with_style(Style::highlight(), |printer| {
+                    printer.with_style(highlight, |printer| {
                         printer.print((0, i as u16), text);
                     });
                 } else {
@@ -112,14 +153,34 @@ impl<S: RedrawState, E: EventLike, T> View<S, E> for SelectView<S, E, T> {
 
     fn layout(
         &mut self,
-        _size: Vec2,
+        size: Vec2,
     ) {
+        self.height = size.y;
     }
 
     fn desired_size(&self) -> Vec2 {
         Vec2::new(self.width, self.btns.len() as u16)
     }
 
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
     fn on_event(
         &mut self,
         state: &mut S,
@@ -140,6 +201,14 @@ impl<S: RedrawState, E: EventLike, T> View<S, E> for SelectView<S, E, T> {
             self.focus_up(state)
         } else if ke.try_down() {
             self.focus_down(state)
+        } else if ke.try_home() {
+            self.select(state, 0)
+        } else if ke.try_end() {
+            self.select(state, self.btns.len())
+        } else if ke.try_page_up() {
+            self.select(state, self.selected.saturating_sub(self.page()))
+        } else if ke.try_page_down() {
+            self.select(state, self.selected + self.page())
         } else {
             None
         }
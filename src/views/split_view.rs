@@ -0,0 +1,466 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyCode,
+        KeyEvent,
+        KeyEventLike,
+        KeyModifier,
+        MouseEventLike,
+    },
+    focus::{
+        cycle_focus,
+        walk_focus,
+        FocusDirection,
+    },
+    orientation::Orientation,
+    printer::Printer,
+    rect::Rect,
+    state::RedrawState,
+    style::Style,
+    vec2::Vec2,
+    view::View,
+};
+
+/// Two children separated by a divider which can be dragged with mouse
+///
+/// [`Orientation::Horizontal`] puts children side by side with a vertical divider,
+/// [`Orientation::Vertical`] stacks them with a horizontal one.
+pub struct SplitView<S, E, M> {
+    children:    [Box<dyn View<S, E, Message = M>>; 2],
+    orientation: Orientation,
+    /// Requested size of first child, half of the view when `None`
+    position:    Option<u16>,
+    /// Size of first child after layout
+    divider:     u16,
+    min_sizes:   [u16; 2],
+    size:        Vec2,
+    dragging:    bool,
+    /// Child which got left button down, it gets mouse events until the button is up
+    pressed:     Option<usize>,
+    shrink_key:  KeyEvent,
+    grow_key:    KeyEvent,
+}
+
+impl<S, E, M> SplitView<S, E, M> {
+    pub fn new(
+        orientation: Orientation,
+        first: impl View<S, E, Message = M> + 'static,
+        second: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        let (shrink, grow) = match orientation {
+            Orientation::Horizontal => (KeyCode::Left, KeyCode::Right),
+            Orientation::Vertical => (KeyCode::Up, KeyCode::Down),
+        };
+
+        Self {
+            children: [Box::new(first), Box::new(second)],
+            orientation,
+            position: None,
+            divider: 0,
+            min_sizes: [0, 0],
+            size: Vec2::new(0, 0),
+            dragging: false,
+            pressed: None,
+            shrink_key: KeyEvent::new(shrink, KeyModifier::Alt),
+            grow_key: KeyEvent::new(grow, KeyModifier::Alt),
+        }
+    }
+
+    /// Children side by side
+    #[inline]
+    pub fn horizontal(
+        first: impl View<S, E, Message = M> + 'static,
+        second: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        Self::new(Orientation::Horizontal, first, second)
+    }
+
+    /// First child above second one
+    #[inline]
+    pub fn vertical(
+        first: impl View<S, E, Message = M> + 'static,
+        second: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        Self::new(Orientation::Vertical, first, second)
+    }
+
+    /// Size of first child, clamped by min sizes on layout
+    #[inline]
+    pub fn position(
+        mut self,
+        position: u16,
+    ) -> Self {
+        self.set_position(position);
+        self
+    }
+
+    #[inline]
+    pub fn set_position(
+        &mut self,
+        position: u16,
+    ) {
+        self.position = Some(position);
+        self.layout_children();
+    }
+
+    /// Position of divider after last layout
+    #[inline]
+    pub fn divider(&self) -> u16 {
+        self.divider
+    }
+
+    /// Children are never made smaller than these while space allows
+    #[inline]
+    pub fn min_sizes(
+        mut self,
+        first: u16,
+        second: u16,
+    ) -> Self {
+        self.set_min_sizes(first, second);
+        self
+    }
+
+    #[inline]
+    pub fn set_min_sizes(
+        &mut self,
+        first: u16,
+        second: u16,
+    ) {
+        self.min_sizes = [first, second];
+    }
+
+    /// Keys which move the divider, `alt-left`/`alt-right` or `alt-up`/`alt-down` by default
+    #[inline]
+    pub fn keys(
+        mut self,
+        shrink: KeyEvent,
+        grow: KeyEvent,
+    ) -> Self {
+        self.set_keys(shrink, grow);
+        self
+    }
+
+    #[inline]
+    pub fn set_keys(
+        &mut self,
+        shrink: KeyEvent,
+        grow: KeyEvent,
+    ) {
+        self.shrink_key = shrink;
+        self.grow_key = grow;
+    }
+
+    #[inline]
+    pub fn first(&self) -> &dyn View<S, E, Message = M> {
+        &*self.children[0]
+    }
+
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut (dyn View<S, E, Message = M> + 'static) {
+        &mut *self.children[0]
+    }
+
+    #[inline]
+    pub fn second(&self) -> &dyn View<S, E, Message = M> {
+        &*self.children[1]
+    }
+
+    #[inline]
+    pub fn second_mut(&mut self) -> &mut (dyn View<S, E, Message = M> + 'static) {
+        &mut *self.children[1]
+    }
+
+    /// Length along the split axis
+    #[inline]
+    fn main_len(&self) -> u16 {
+        match self.orientation {
+            Orientation::Horizontal => self.size.x,
+            Orientation::Vertical => self.size.y,
+        }
+    }
+
+    #[inline]
+    fn main_pos(
+        &self,
+        pos: Vec2,
+    ) -> u16 {
+        match self.orientation {
+            Orientation::Horizontal => pos.x,
+            Orientation::Vertical => pos.y,
+        }
+    }
+
+    fn rects(&self) -> [Rect; 2] {
+        let size = self.size;
+        let first = self.divider;
+        let second = self.main_len().saturating_sub(first + 1);
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                [
+                    Rect::new((0, 0), (first, size.y)),
+                    Rect::new((first + 1, 0), (second, size.y)),
+                ]
+            }
+            Orientation::Vertical => {
+                [
+                    Rect::new((0, 0), (size.x, first)),
+                    Rect::new((0, first + 1), (size.x, second)),
+                ]
+            }
+        }
+    }
+
+    fn layout_children(&mut self) {
+        // One cell is taken by divider
+        let available = self.main_len().saturating_sub(1);
+        let position = self.position.unwrap_or(available / 2);
+
+        self.divider = position
+            .min(available.saturating_sub(self.min_sizes[1]))
+            .max(self.min_sizes[0].min(available));
+
+        let rects = self.rects();
+        for (child, rect) in self.children.iter_mut().zip(rects) {
+            child.layout(rect.size());
+        }
+    }
+
+    /// Move divider to `position`, returns `false` when it didn't move
+    fn move_divider(
+        &mut self,
+        position: u16,
+    ) -> bool {
+        let old = self.divider;
+        self.position = Some(position);
+        self.layout_children();
+        // Keep requested position in range so keys respond immediately
+        self.position = Some(self.divider);
+        self.divider != old
+    }
+
+    #[inline]
+    fn focused_child(&self) -> Option<usize> {
+        self.children.iter().position(|c| c.has_focus())
+    }
+
+    /// Move focus to next child which takes it, wraps around at the end when `wrap` is set
+    fn step_focus(
+        &mut self,
+        direction: FocusDirection,
+        wrap: bool,
+    ) -> bool {
+        let current = self.focused_child();
+        let children = &mut self.children;
+        let take = |i: usize, direction| children[i].take_focus(direction);
+
+        if wrap {
+            cycle_focus(2, current, direction, take).is_some()
+        } else {
+            walk_focus(2, current, direction, take).is_some()
+        }
+    }
+}
+
+impl<S: RedrawState, E: EventLike, M> View<S, E> for SplitView<S, E, M> {
+    type Message = M;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let start = printer.bound().start();
+
+        for (child, rect) in self.children.iter().zip(self.rects()) {
+            printer.with_bound(Rect::new(start + rect.start(), rect.size()), |printer| {
+                child.render(printer)
+            });
+        }
+
+        let style = if self.dragging {
+            Style::highlight()
+        } else {
+            Style::outline()
+        };
+
+        printer.with_style(style, |printer| {
+            match self.orientation {
+                Orientation::Horizontal => printer.print_vertical_line(self.divider),
+                Orientation::Vertical => printer.print_horizontal_line(self.divider),
+            }
+        });
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+        self.layout_children();
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        let first = self.children[0].desired_size();
+        let second = self.children[1].desired_size();
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                Vec2::new(
+                    first.x.saturating_add(second.x).saturating_add(1),
+                    first.y.max(second.y),
+                )
+            }
+            Orientation::Vertical => {
+                Vec2::new(
+                    first.x.max(second.x),
+                    first.y.saturating_add(second.y).saturating_add(1),
+                )
+            }
+        }
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        self.step_focus(direction, false)
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused_child().is_some()
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        for child in self.children.iter_mut() {
+            child.release_focus();
+        }
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.focused_child()
+            .is_some_and(|i| self.children[i].has_pending_keys())
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        let i = self.focused_child()?;
+        let rect = self.children[i].focus_rect()?;
+        Some(Rect::new(
+            self.rects()[i].start() + rect.start(),
+            rect.size(),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        mut event: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = event.try_mouse_mut() {
+            if self.dragging {
+                if let Some(pos) = me.try_drag() {
+                    if self.move_divider(self.main_pos(pos)) {
+                        state.set_need_redraw(true);
+                    }
+                } else if me.try_left_up().is_some() {
+                    self.dragging = false;
+                    state.set_need_redraw(true);
+                }
+                return None;
+            }
+
+            let is_click = me.try_left_down().is_some();
+
+            // Keep drags inside a child, e.g. of a nested split, going to it outside of its rect
+            if let Some(i) = self.pressed.filter(|_| !is_click) {
+                if me.try_left_up().is_some() {
+                    self.pressed = None;
+                }
+
+                let start = self.rects()[i].start();
+                me.map_pos(|pos| pos.saturating_sub(start));
+                return self.children[i].on_event(state, event);
+            }
+
+            let pos = me.pos();
+            self.pressed = None;
+
+            if is_click && self.main_pos(pos) == self.divider {
+                self.dragging = true;
+                state.set_need_redraw(true);
+                return None;
+            }
+
+            let rects = self.rects();
+            let i = rects.iter().position(|rect| rect.contains(pos))?;
+            let start = rects[i].start();
+            me.map_pos(|pos| pos - start);
+
+            if is_click {
+                self.pressed = Some(i);
+
+                if !self.children[i].has_focus()
+                    && self.children[i].take_focus(FocusDirection::Forward)
+                {
+                    self.children[1 - i].release_focus();
+                    state.set_need_redraw(true);
+                }
+            }
+
+            return self.children[i].on_event(state, event);
+        }
+
+        if let Some(ke) = event.try_key() {
+            let key = ke.key();
+
+            if key == self.shrink_key || key == self.grow_key {
+                // Focused child gets the key first, it moves the divider when the child doesn't
+                // use it
+                if let Some(i) = self.focused_child() {
+                    let redraw = state.is_need_redraw();
+                    state.set_need_redraw(false);
+
+                    let msg = self.children[i].on_event(state, event);
+                    let used = msg.is_some() || state.is_need_redraw();
+                    state.set_need_redraw(redraw || used);
+
+                    if used || self.children[i].has_pending_keys() {
+                        return msg;
+                    }
+                }
+
+                let position = if key == self.grow_key {
+                    self.divider.saturating_add(1)
+                } else {
+                    self.divider.saturating_sub(1)
+                };
+
+                if self.move_divider(position) {
+                    state.set_need_redraw(true);
+                }
+                return None;
+            }
+
+            let direction = if ke.try_tab() {
+                Some(FocusDirection::Forward)
+            } else if ke.try_back_tab() {
+                Some(FocusDirection::Backward)
+            } else {
+                None
+            };
+
+            if let Some(direction) = direction {
+                if self.step_focus(direction, true) {
+                    state.set_need_redraw(true);
+                    return None;
+                }
+            }
+        }
+
+        let i = self.focused_child()?;
+        self.children[i].on_event(state, event)
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyCode,
+        KeyEvent,
+        KeyEventLike,
+        KeyModifier,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
+    printer::Printer,
+    rect::Rect,
+    state::RedrawState,
+    style::Style,
+    vec2::Vec2,
+    view::View,
+};
+use unicode_width::UnicodeWidthStr;
+
+const SEPARATOR: &str = "│";
+
+type OnChange<S, M> = Box<dyn FnMut(&mut S, usize) -> M>;
+
+struct Tab<S, E, M> {
+    title: String,
+    view:  Box<dyn View<S, E, Message = M>>,
+}
+
+impl<S, E, M> Tab<S, E, M> {
+    /// Width of title with padding
+    #[inline]
+    fn width(&self) -> u16 {
+        self.title.width() as u16 + 2
+    }
+}
+
+/// Shows one of children under a tab bar
+///
+/// Only the active child gets events and layout.
+pub struct TabView<S, E, M> {
+    tabs:      Vec<Tab<S, E, M>>,
+    active:    usize,
+    size:      Vec2,
+    prev_key:  KeyEvent,
+    next_key:  KeyEvent,
+    on_change: Option<OnChange<S, M>>,
+}
+
+impl<S, E, M> Default for TabView<S, E, M> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, E, M> TabView<S, E, M> {
+    pub fn new() -> Self {
+        Self {
+            tabs:      Vec::with_capacity(5),
+            active:    0,
+            size:      Vec2::new(0, 0),
+            prev_key:  KeyEvent::new(KeyCode::PageUp, KeyModifier::Control),
+            next_key:  KeyEvent::new(KeyCode::PageDown, KeyModifier::Control),
+            on_change: None,
+        }
+    }
+
+    #[inline]
+    pub fn tab(
+        mut self,
+        title: impl Into<String>,
+        view: impl View<S, E, Message = M> + 'static,
+    ) -> Self {
+        self.add_tab(title, view);
+        self
+    }
+
+    pub fn add_tab(
+        &mut self,
+        title: impl Into<String>,
+        view: impl View<S, E, Message = M> + 'static,
+    ) {
+        self.tabs.push(Tab {
+            title: title.into(),
+            view:  Box::new(view),
+        });
+    }
+
+    /// Keys which switch to previous and next tab, `ctrl-pageup` and `ctrl-pagedown` by default
+    #[inline]
+    pub fn keys(
+        mut self,
+        prev: KeyEvent,
+        next: KeyEvent,
+    ) -> Self {
+        self.set_keys(prev, next);
+        self
+    }
+
+    #[inline]
+    pub fn set_keys(
+        &mut self,
+        prev: KeyEvent,
+        next: KeyEvent,
+    ) {
+        self.prev_key = prev;
+        self.next_key = next;
+    }
+
+    /// Message returned when active tab is changed by user
+    #[inline]
+    pub fn on_change(
+        mut self,
+        f: impl FnMut(&mut S, usize) -> M + 'static,
+    ) -> Self {
+        self.set_on_change(f);
+        self
+    }
+
+    #[inline]
+    pub fn set_on_change(
+        &mut self,
+        f: impl FnMut(&mut S, usize) -> M + 'static,
+    ) {
+        self.on_change = Some(Box::new(f));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    #[inline]
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    #[inline]
+    pub fn active_view(&self) -> Option<&dyn View<S, E, Message = M>> {
+        self.tabs.get(self.active).map(|tab| &*tab.view)
+    }
+
+    #[inline]
+    pub fn active_view_mut(&mut self) -> Option<&mut (dyn View<S, E, Message = M> + 'static)> {
+        self.tabs.get_mut(self.active).map(|tab| &mut *tab.view)
+    }
+
+    /// Switch to tab at `index`, focus moves to new tab when old tab had it
+    ///
+    /// Returns `false` when `index` is out of range or already active
+    pub fn set_active(
+        &mut self,
+        index: usize,
+    ) -> bool {
+        if index >= self.tabs.len() || index == self.active {
+            return false;
+        }
+
+        let old = &mut self.tabs[self.active].view;
+        let focused = old.has_focus();
+        old.release_focus();
+
+        self.active = index;
+
+        let new = &mut self.tabs[index].view;
+        if focused {
+            new.take_focus(FocusDirection::Forward);
+        }
+        new.layout(self.size.saturating_sub_y(1));
+
+        true
+    }
+
+    fn change(
+        &mut self,
+        state: &mut S,
+        index: usize,
+    ) -> Option<M>
+    where
+        S: RedrawState,
+    {
+        if !self.set_active(index) {
+            return None;
+        }
+
+        state.set_need_redraw(true);
+        let f = self.on_change.as_mut()?;
+        Some(f(state, index))
+    }
+
+    fn tab_at(
+        &self,
+        x: u16,
+    ) -> Option<usize> {
+        let mut start = 0;
+
+        for (i, tab) in self.tabs.iter().enumerate() {
+            let end = start + tab.width();
+            if x < end {
+                return if x >= start { Some(i) } else { None };
+            }
+            // Skip separator
+            start = end + 1;
+        }
+
+        None
+    }
+
+    fn bar_width(&self) -> u16 {
+        let titles: u16 = self.tabs.iter().map(Tab::width).sum();
+        titles + self.tabs.len().saturating_sub(1) as u16
+    }
+}
+
+impl<S, E, M> View<S, E> for TabView<S, E, M>
+where
+    S: RedrawState,
+    E: EventLike,
+{
+    type Message = M;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let tab = match self.tabs.get(self.active) {
+            Some(tab) => tab,
+            None => return,
+        };
+        let highlight = if tab.view.has_focus() {
+            Style::highlight()
+        } else {
+            Style::highlight_inactive()
+        };
+
+        printer.with_style(Style::view(), |printer| {
+            let mut x = 0;
+
+            for (i, tab) in self.tabs.iter().enumerate() {
+                if i > 0 {
+                    printer.print((x, 0), SEPARATOR);
+                    x += 1;
+                }
+
+                let title = format!(" {} ", tab.title);
+
+                if i == self.active {
+                    printer.with_style(highlight, |printer| printer.print((x, 0), &title));
+                } else {
+                    printer.print((x, 0), &title);
+                }
+
+                x += tab.width();
+            }
+        });
+
+        let bound = printer.bound();
+        printer.with_bound(
+            Rect::new(bound.start().add_y(1), bound.size().saturating_sub_y(1)),
+            |printer| tab.view.render(printer),
+        );
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+
+        if let Some(tab) = self.tabs.get_mut(self.active) {
+            tab.view.layout(size.saturating_sub_y(1));
+        }
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        let content = self
+            .tabs
+            .get(self.active)
+            .map_or(Vec2::new(0, 0), |tab| tab.view.desired_size());
+
+        Vec2::new(content.x.max(self.bar_width()), content.y.saturating_add(1))
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        direction: FocusDirection,
+    ) -> bool {
+        match self.tabs.get_mut(self.active) {
+            Some(tab) => tab.view.take_focus(direction),
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.tabs
+            .get(self.active)
+            .is_some_and(|tab| tab.view.has_focus())
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        if let Some(tab) = self.tabs.get_mut(self.active) {
+            tab.view.release_focus();
+        }
+    }
+
+    #[inline]
+    fn has_pending_keys(&self) -> bool {
+        self.tabs
+            .get(self.active)
+            .is_some_and(|tab| tab.view.has_pending_keys())
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        let rect = self.tabs.get(self.active)?.view.focus_rect()?;
+        Some(Rect::new(rect.start().add_y(1), rect.size()))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        mut event: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = event.try_mouse_mut() {
+            if let Some(pos) = me.try_left_down() {
+                if pos.y == 0 {
+                    let index = self.tab_at(pos.x)?;
+                    return self.change(state, index);
+                }
+            }
+
+            if !me.filter_map_pos(|pos| pos.y.checked_sub(1).map(|y| Vec2::new(pos.x, y))) {
+                return None;
+            }
+        } else if let Some(ke) = event.try_key() {
+            let key = ke.key();
+            let len = self.tabs.len();
+
+            if len > 0 && key == self.next_key {
+                return self.change(state, (self.active + 1) % len);
+            } else if len > 0 && key == self.prev_key {
+                return self.change(state, (self.active + len - 1) % len);
+            }
+        }
+
+        self.tabs.get_mut(self.active)?.view.on_event(state, event)
+    }
+}
@@ -0,0 +1,547 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyEventLike,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
+    layout::{
+        solve,
+        Constraint,
+        TextAlign,
+    },
+    printer::Printer,
+    rect::Rect,
+    state::RedrawState,
+    style::Style,
+    vec2::Vec2,
+    view::View,
+};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    marker::PhantomData,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Row of [`TableView`]
+pub trait TableRow {
+    /// Text of `column`, borrow it when the row already holds the text
+    fn cell(
+        &self,
+        column: usize,
+    ) -> Cow<'_, str>;
+
+    /// Order used when table is sorted by `column`, compares cell text by default
+    fn compare(
+        &self,
+        other: &Self,
+        column: usize,
+    ) -> Ordering {
+        self.cell(column).cmp(&other.cell(column))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    #[inline]
+    fn arrow(self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "▲",
+            SortOrder::Descending => "▼",
+        }
+    }
+}
+
+pub struct TableColumn {
+    title:      String,
+    constraint: Constraint,
+    align:      TextAlign,
+    /// Widest of title and cells
+    content:    u16,
+    x:          u16,
+    width:      u16,
+}
+
+impl TableColumn {
+    pub fn new(title: impl Into<String>) -> Self {
+        let title = title.into();
+        Self {
+            // Space for sort arrow
+            content: title.width() as u16 + 1,
+            title,
+            constraint: Constraint::Auto,
+            align: TextAlign::Left,
+            x: 0,
+            width: 0,
+        }
+    }
+
+    #[inline]
+    pub fn constraint(
+        mut self,
+        constraint: Constraint,
+    ) -> Self {
+        self.constraint = constraint;
+        self
+    }
+
+    /// Alignment of title and cells, `Justify` is same as `Left`
+    #[inline]
+    pub fn align(
+        mut self,
+        align: TextAlign,
+    ) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn offset(
+        &self,
+        text: &str,
+    ) -> u16 {
+        let rest = self.width.saturating_sub(text.width() as u16);
+
+        match self.align {
+            TextAlign::Left | TextAlign::Justify => 0,
+            TextAlign::Center => rest / 2,
+            TextAlign::Right => rest,
+        }
+    }
+}
+
+/// Table with a header row, only visible rows are rendered
+pub struct TableView<S, E, Row> {
+    columns:  Vec<TableColumn>,
+    rows:     Vec<Row>,
+    /// Index of row at each display position
+    order:    Vec<usize>,
+    sort:     Option<(usize, SortOrder)>,
+    /// Display position of selected row
+    selected: usize,
+    /// Display position of first visible row
+    scroll:   usize,
+    size:     Vec2,
+    focused:  bool,
+    _marker:  PhantomData<(S, E)>,
+}
+
+impl<S: RedrawState, E, Row: TableRow> Default for TableView<S, E, Row> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: RedrawState, E, Row: TableRow> TableView<S, E, Row> {
+    pub fn new() -> Self {
+        Self {
+            columns:  Vec::new(),
+            rows:     Vec::new(),
+            order:    Vec::new(),
+            sort:     None,
+            selected: 0,
+            scroll:   0,
+            size:     Vec2::new(0, 0),
+            focused:  false,
+            _marker:  PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn column(
+        mut self,
+        column: TableColumn,
+    ) -> Self {
+        self.add_column(column);
+        self
+    }
+
+    pub fn add_column(
+        &mut self,
+        mut column: TableColumn,
+    ) {
+        let index = self.columns.len();
+        for row in self.rows.iter() {
+            column.content = column.content.max(row.cell(index).width() as u16);
+        }
+        self.columns.push(column);
+    }
+
+    #[inline]
+    pub fn rows(
+        mut self,
+        rows: Vec<Row>,
+    ) -> Self {
+        self.set_rows(rows);
+        self
+    }
+
+    /// Replace all rows, selection moves to the first row and current sort is kept
+    pub fn set_rows(
+        &mut self,
+        rows: Vec<Row>,
+    ) {
+        self.rows = rows;
+        self.order = (0..self.rows.len()).collect();
+        self.selected = 0;
+        self.scroll = 0;
+
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            column.content = self
+                .rows
+                .iter()
+                .map(|row| row.cell(i).width() as u16)
+                .fold(column.title.width() as u16 + 1, u16::max);
+        }
+
+        if let Some((column, order)) = self.sort {
+            self.sort(column, order);
+        }
+    }
+
+    /// Add a row, it is placed by current sort
+    pub fn push_row(
+        &mut self,
+        row: Row,
+    ) {
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            column.content = column.content.max(row.cell(i).width() as u16);
+        }
+
+        let index = self.rows.len();
+        let pos = match self.sort {
+            Some((column, order)) => {
+                let rows = &self.rows;
+                self.order.partition_point(|&i| {
+                    compare(&rows[i], &row, column, order) != Ordering::Greater
+                })
+            }
+            None => index,
+        };
+
+        self.rows.push(row);
+        self.order.insert(pos, index);
+
+        if pos <= self.selected && self.rows.len() > 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Sort rows by `column`, selected row stays selected
+    pub fn sort(
+        &mut self,
+        column: usize,
+        order: SortOrder,
+    ) {
+        let selected = self.order.get(self.selected).copied();
+        let rows = &self.rows;
+
+        self.order
+            .sort_by(|&a, &b| compare(&rows[a], &rows[b], column, order));
+        self.sort = Some((column, order));
+
+        if let Some(selected) = selected {
+            self.selected = self.order.iter().position(|&i| i == selected).unwrap_or(0);
+            self.scroll_to_selected();
+        }
+    }
+
+    #[inline]
+    pub fn sorted_by(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Row by its insertion index
+    #[inline]
+    pub fn row(
+        &self,
+        index: usize,
+    ) -> Option<&Row> {
+        self.rows.get(index)
+    }
+
+    /// Insertion index of selected row
+    #[inline]
+    pub fn selected_index(&self) -> Option<usize> {
+        self.order.get(self.selected).copied()
+    }
+
+    #[inline]
+    pub fn selected_row(&self) -> Option<&Row> {
+        self.rows.get(self.selected_index()?)
+    }
+
+    #[inline]
+    pub fn selected_row_mut(&mut self) -> Option<&mut Row> {
+        let index = self.selected_index()?;
+        self.rows.get_mut(index)
+    }
+
+    /// Select row at display position `pos`
+    pub fn select(
+        &mut self,
+        state: &mut S,
+        pos: usize,
+    ) -> Option<TableViewMessage> {
+        let pos = pos.min(self.rows.len().saturating_sub(1));
+
+        if pos == self.selected {
+            None
+        } else {
+            self.selected = pos;
+            self.scroll_to_selected();
+            state.set_need_redraw(true);
+            Some(TableViewMessage::IndexChanged)
+        }
+    }
+
+    /// Number of visible rows
+    #[inline]
+    fn page(&self) -> usize {
+        self.size.y.saturating_sub(1).max(1) as usize
+    }
+
+    fn scroll_to_selected(&mut self) {
+        let page = self.page();
+
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + page {
+            self.scroll = self.selected + 1 - page;
+        }
+    }
+
+    fn column_at(
+        &self,
+        x: u16,
+    ) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|column| x >= column.x && x < column.x + column.width)
+    }
+
+    fn render_row(
+        &self,
+        printer: &mut Printer,
+        y: u16,
+        row: &Row,
+    ) {
+        let start = printer.bound().start();
+
+        for (i, column) in self.columns.iter().enumerate() {
+            let text = row.cell(i);
+            let bound = Rect::new(start + Vec2::new(column.x, y), (column.width, 1));
+
+            printer.with_bound(bound, |printer| {
+                printer.print((column.offset(&text), 0), &text);
+            });
+        }
+    }
+}
+
+fn compare<Row: TableRow>(
+    a: &Row,
+    b: &Row,
+    column: usize,
+    order: SortOrder,
+) -> Ordering {
+    match order {
+        SortOrder::Ascending => a.compare(b, column),
+        SortOrder::Descending => b.compare(a, column),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TableViewMessage {
+    Select,
+    IndexChanged,
+    /// Header is clicked, see [`TableView::sorted_by`]
+    SortChanged,
+}
+
+impl<S: RedrawState, E: EventLike, Row: TableRow> View<S, E> for TableView<S, E, Row> {
+    type Message = TableViewMessage;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let start = printer.bound().start();
+        let width = self.size.x as usize;
+        let highlight = if self.focused {
+            Style::highlight()
+        } else {
+            Style::highlight_inactive()
+        };
+
+        printer.with_style(Style::title(), |printer| {
+            for (i, column) in self.columns.iter().enumerate() {
+                let mut title = column.title.clone();
+
+                if let Some((_, order)) = self.sort.filter(|&(sorted, _)| sorted == i) {
+                    title.push_str(order.arrow());
+                }
+
+                let bound = Rect::new(start + Vec2::new(column.x, 0), (column.width, 1));
+                printer.with_bound(bound, |printer| {
+                    printer.print((column.offset(&title), 0), &title);
+                });
+            }
+        });
+
+        printer.with_style(Style::view(), |printer| {
+            let visible = self.order.iter().skip(self.scroll).take(self.page());
+
+            for (y, (pos, &index)) in (1..).zip((self.scroll..).zip(visible)) {
+                let row = &self.rows[index];
+
+                if pos == self.selected {
+                    printer.with_style(highlight, |printer| {
+                        printer.print((0, y), &" ".repeat(width));
+                        self.render_row(printer, y, row);
+                    });
+                } else {
+                    self.render_row(printer, y, row);
+                }
+            }
+        });
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+
+        let gaps = self.columns.len().saturating_sub(1) as u16;
+        let items: Vec<_> = self
+            .columns
+            .iter()
+            .map(|column| (column.constraint, column.content))
+            .collect();
+        let widths = solve(&items, size.x.saturating_sub(gaps));
+
+        let mut x = 0;
+        for (column, width) in self.columns.iter_mut().zip(widths) {
+            column.x = x;
+            column.width = width;
+            x += width + 1;
+        }
+
+        let max_scroll = self.rows.len().saturating_sub(self.page());
+        self.scroll = self.scroll.min(max_scroll);
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        let gaps = self.columns.len().saturating_sub(1) as u16;
+        let width = self
+            .columns
+            .iter()
+            .map(|column| column.constraint.desired(column.content))
+            .fold(gaps, u16::saturating_add);
+        let height = (self.rows.len() + 1).min(u16::MAX as usize) as u16;
+
+        Vec2::new(width, height)
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        e: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = e.try_mouse() {
+            if let Some(pos) = me.try_left_down() {
+                if pos.y == 0 {
+                    let column = self.column_at(pos.x)?;
+                    let order = match self.sort {
+                        Some((sorted, SortOrder::Ascending)) if sorted == column => {
+                            SortOrder::Descending
+                        }
+                        _ => SortOrder::Ascending,
+                    };
+                    self.sort(column, order);
+                    state.set_need_redraw(true);
+                    return Some(TableViewMessage::SortChanged);
+                }
+
+                let pos = self.scroll + pos.y as usize - 1;
+
+                if pos >= self.rows.len() {
+                    return None;
+                }
+
+                return self.select(state, pos).or(Some(TableViewMessage::Select));
+            } else if me.try_scroll_up().is_some() {
+                return self.select(state, self.selected.saturating_sub(1));
+            } else if me.try_scroll_down().is_some() {
+                return self.select(state, self.selected + 1);
+            }
+
+            return None;
+        }
+
+        let ke = e.try_key()?;
+
+        if ke.try_enter() {
+            if self.rows.is_empty() {
+                None
+            } else {
+                Some(TableViewMessage::Select)
+            }
+        } else if ke.try_up() {
+            self.select(state, self.selected.saturating_sub(1))
+        } else if ke.try_down() {
+            self.select(state, self.selected + 1)
+        } else if ke.try_home() {
+            self.select(state, 0)
+        } else if ke.try_end() {
+            self.select(state, self.rows.len())
+        } else if ke.try_page_up() {
+            self.select(state, self.selected.saturating_sub(self.page()))
+        } else if ke.try_page_down() {
+            self.select(state, self.selected + self.page())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,533 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyCode,
+        KeyEventLike,
+        KeyModifier,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
+    printer::Printer,
+    state::RedrawState,
+    style::{
+        Effect,
+        Style,
+    },
+    util::{
+        find_str_width_pos,
+        next_char_pos,
+        next_word_pos,
+        prev_char_pos,
+        prev_word_pos,
+        slice_str_with_width,
+    },
+    vec2::Vec2,
+    view::View,
+};
+use std::marker::PhantomData;
+use unicode_width::{
+    UnicodeWidthChar,
+    UnicodeWidthStr,
+};
+
+/// Multi-line text editor
+///
+/// Lines that are wider than the view are scrolled horizontally.
+/// `Enter` inserts a new line, `Alt-Enter` or `Ctrl-Enter` submits.
+pub struct TextAreaView<S, E> {
+    lines:    Vec<String>,
+    /// Line index and byte offset in that line
+    cursor:   (usize, usize),
+    /// Column `Up` and `Down` try to keep
+    goal:     Option<usize>,
+    scroll_x: usize,
+    scroll_y: usize,
+    size:     Vec2,
+    focused:  bool,
+    _marker:  PhantomData<(S, E)>,
+}
+
+impl<S, E> Default for TextAreaView<S, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, E> TextAreaView<S, E> {
+    pub fn new() -> Self {
+        Self {
+            lines:    vec![String::new()],
+            cursor:   (0, 0),
+            goal:     None,
+            scroll_x: 0,
+            scroll_y: 0,
+            size:     Vec2::new(0, 0),
+            focused:  false,
+            _marker:  PhantomData,
+        }
+    }
+
+    pub fn with_text(text: &str) -> Self {
+        let mut view = Self::new();
+        view.set_text(text);
+        view
+    }
+
+    /// Replace whole text, cursor goes to the start
+    pub fn set_text(
+        &mut self,
+        text: &str,
+    ) {
+        self.lines = text.split('\n').map(String::from).collect();
+        self.cursor = (0, 0);
+        self.goal = None;
+        self.scroll_x = 0;
+        self.scroll_y = 0;
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    #[inline]
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Line index and byte offset of cursor
+    #[inline]
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Move cursor, out of range position is clamped
+    pub fn set_cursor(
+        &mut self,
+        line: usize,
+        pos: usize,
+    ) {
+        let line = line.min(self.lines.len() - 1);
+        let text = &self.lines[line];
+        let mut pos = pos.min(text.len());
+
+        while !text.is_char_boundary(pos) {
+            pos -= 1;
+        }
+
+        self.cursor = (line, pos);
+        self.goal = None;
+        self.scroll_to_cursor();
+    }
+
+    #[inline]
+    fn line(&self) -> &str {
+        &self.lines[self.cursor.0]
+    }
+
+    #[inline]
+    fn cursor_col(&self) -> usize {
+        self.line()[..self.cursor.1].width()
+    }
+
+    #[inline]
+    fn page(&self) -> usize {
+        self.size.y.max(1) as usize
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let col = self.cursor_col();
+        let cursor_width = self.line()[self.cursor.1..]
+            .chars()
+            .next()
+            .and_then(|ch| ch.width())
+            .unwrap_or(1)
+            .max(1);
+        let width = self.size.x.max(1) as usize;
+        let height = self.page();
+
+        if col < self.scroll_x {
+            self.scroll_x = col;
+        } else if col + cursor_width > self.scroll_x + width {
+            self.scroll_x = (col + cursor_width).saturating_sub(width);
+        }
+
+        if self.cursor.0 < self.scroll_y {
+            self.scroll_y = self.cursor.0;
+        } else if self.cursor.0 >= self.scroll_y + height {
+            self.scroll_y = self.cursor.0 + 1 - height;
+        }
+    }
+
+    /// Move cursor to `line` keeping its column
+    fn move_line(
+        &mut self,
+        line: usize,
+    ) -> bool {
+        let line = line.min(self.lines.len() - 1);
+
+        if line == self.cursor.0 {
+            return false;
+        }
+
+        let goal = match self.goal {
+            Some(goal) => goal,
+            None => self.cursor_col(),
+        };
+
+        self.cursor = (line, find_str_width_pos(&self.lines[line], goal).0);
+        self.goal = Some(goal);
+        true
+    }
+
+    fn move_to(
+        &mut self,
+        line: usize,
+        pos: usize,
+    ) -> bool {
+        self.goal = None;
+
+        if self.cursor == (line, pos) {
+            false
+        } else {
+            self.cursor = (line, pos);
+            true
+        }
+    }
+
+    fn move_left(&mut self) -> bool {
+        let (line, pos) = self.cursor;
+
+        if pos > 0 {
+            self.move_to(line, prev_char_pos(self.line(), pos))
+        } else if line > 0 {
+            self.move_to(line - 1, self.lines[line - 1].len())
+        } else {
+            false
+        }
+    }
+
+    fn move_right(&mut self) -> bool {
+        let (line, pos) = self.cursor;
+
+        if pos < self.line().len() {
+            self.move_to(line, next_char_pos(self.line(), pos))
+        } else if line + 1 < self.lines.len() {
+            self.move_to(line + 1, 0)
+        } else {
+            false
+        }
+    }
+
+    fn move_word_left(&mut self) -> bool {
+        let (line, pos) = self.cursor;
+
+        if pos > 0 {
+            self.move_to(line, prev_word_pos(self.line(), pos))
+        } else {
+            self.move_left()
+        }
+    }
+
+    fn move_word_right(&mut self) -> bool {
+        let (line, pos) = self.cursor;
+
+        if pos < self.line().len() {
+            self.move_to(line, next_word_pos(self.line(), pos))
+        } else {
+            self.move_right()
+        }
+    }
+
+    fn insert_char(
+        &mut self,
+        ch: char,
+    ) {
+        let (line, pos) = self.cursor;
+        self.lines[line].insert(pos, ch);
+        self.move_to(line, pos + ch.len_utf8());
+    }
+
+    fn insert_newline(&mut self) {
+        let (line, pos) = self.cursor;
+        let tail = self.lines[line].split_off(pos);
+        self.lines.insert(line + 1, tail);
+        self.move_to(line + 1, 0);
+    }
+
+    /// Remove text from `start` to cursor in current line
+    fn remove_before(
+        &mut self,
+        start: usize,
+    ) {
+        let (line, pos) = self.cursor;
+        self.lines[line].replace_range(start..pos, "");
+        self.move_to(line, start);
+    }
+
+    /// Join current line to the previous line
+    fn join_prev(&mut self) -> bool {
+        let line = self.cursor.0;
+
+        if line == 0 {
+            return false;
+        }
+
+        let text = self.lines.remove(line);
+        let pos = self.lines[line - 1].len();
+        self.lines[line - 1].push_str(&text);
+        self.move_to(line - 1, pos)
+    }
+
+    fn delete_backward(&mut self) -> bool {
+        let pos = self.cursor.1;
+
+        if pos > 0 {
+            self.remove_before(prev_char_pos(self.line(), pos));
+            true
+        } else {
+            self.join_prev()
+        }
+    }
+
+    fn delete_word_backward(&mut self) -> bool {
+        let pos = self.cursor.1;
+
+        if pos > 0 {
+            self.remove_before(prev_word_pos(self.line(), pos));
+            true
+        } else {
+            self.join_prev()
+        }
+    }
+
+    fn delete_forward(&mut self) -> bool {
+        let (line, pos) = self.cursor;
+
+        if pos < self.line().len() {
+            let end = next_char_pos(self.line(), pos);
+            self.lines[line].replace_range(pos..end, "");
+            self.goal = None;
+            true
+        } else if line + 1 < self.lines.len() {
+            let text = self.lines.remove(line + 1);
+            self.lines[line].push_str(&text);
+            self.goal = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move cursor to the cell at `pos` in view coordinate
+    fn click(
+        &mut self,
+        pos: Vec2,
+    ) -> bool {
+        let line = (self.scroll_y + pos.y as usize).min(self.lines.len() - 1);
+        let col = self.scroll_x + pos.x as usize;
+        self.move_to(line, find_str_width_pos(&self.lines[line], col).0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextAreaViewMessage {
+    Edit,
+    Submit,
+}
+
+impl<S: RedrawState, E: EventLike> View<S, E> for TextAreaView<S, E> {
+    type Message = TextAreaViewMessage;
+
+    fn desired_size(&self) -> Vec2 {
+        let width = self.lines.iter().map(|l| l.width()).max().unwrap_or(0) + 1;
+        let height = self.lines.len();
+
+        Vec2::new(
+            width.min(u16::MAX as usize) as u16,
+            height.min(u16::MAX as usize) as u16,
+        )
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.size = size;
+        self.scroll_to_cursor();
+    }
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        printer.with_style(Style::view(), |printer| {
+            let lines = self
+                .lines
+                .iter()
+                .skip(self.scroll_y)
+                .take(self.size.y as usize);
+
+            for (y, line) in lines.enumerate() {
+                let (_, mut visible, cut) = slice_str_with_width(line, self.scroll_x);
+                let mut x = 0;
+
+                // Wide character is cut by left edge
+                if cut > 0 {
+                    if let Some(ch) = visible.chars().next() {
+                        visible = &visible[ch.len_utf8()..];
+                        x = (ch.width().unwrap_or(0) - cut) as u16;
+                    }
+                }
+
+                printer.print((x, y as u16), visible);
+            }
+
+            if self.focused && self.cursor.0 >= self.scroll_y {
+                let x = (self.cursor_col() - self.scroll_x) as u16;
+                let y = (self.cursor.0 - self.scroll_y) as u16;
+                let ch = self.line()[self.cursor.1..].chars().next().unwrap_or(' ');
+                let mut buf = [0; 4];
+
+                printer.with_style(Style::view().effects(Effect::Reverse), |printer| {
+                    printer.print((x, y), ch.encode_utf8(&mut buf));
+                });
+            }
+        });
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        e: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = e.try_mouse() {
+            let pos = me.try_left_down().or_else(|| me.try_drag())?;
+
+            if self.click(pos) {
+                self.scroll_to_cursor();
+                state.set_need_redraw(true);
+            }
+
+            return None;
+        }
+
+        let ke = e.try_key()?;
+
+        let edited = if ke.is_key(KeyCode::Enter, KeyModifier::Alt)
+            || ke.is_key(KeyCode::Enter, KeyModifier::Control)
+        {
+            return Some(TextAreaViewMessage::Submit);
+        } else if ke.try_enter() {
+            self.insert_newline();
+            true
+        } else if let Some(ch) = ke.try_char() {
+            self.insert_char(ch);
+            true
+        } else if ke.try_backspace() {
+            self.delete_backward()
+        } else if ke.try_delete() {
+            self.delete_forward()
+        } else if ke.try_ctrl_char() == Some('w') || ke.is_key(KeyCode::Backspace, KeyModifier::Alt)
+        {
+            self.delete_word_backward()
+        } else {
+            let moved = if ke.try_left() {
+                self.move_left()
+            } else if ke.try_right() {
+                self.move_right()
+            } else if ke.is_key(KeyCode::Left, KeyModifier::Control) {
+                self.move_word_left()
+            } else if ke.is_key(KeyCode::Right, KeyModifier::Control) {
+                self.move_word_right()
+            } else if ke.try_up() {
+                self.move_line(self.cursor.0.saturating_sub(1))
+            } else if ke.try_down() {
+                self.move_line(self.cursor.0 + 1)
+            } else if ke.try_page_up() {
+                self.move_line(self.cursor.0.saturating_sub(self.page()))
+            } else if ke.try_page_down() {
+                self.move_line(self.cursor.0 + self.page())
+            } else if ke.try_home() {
+                self.move_to(self.cursor.0, 0)
+            } else if ke.try_end() {
+                self.move_to(self.cursor.0, self.line().len())
+            } else if ke.is_key(KeyCode::Home, KeyModifier::Control) {
+                self.move_to(0, 0)
+            } else if ke.is_key(KeyCode::End, KeyModifier::Control) {
+                let line = self.lines.len() - 1;
+                self.move_to(line, self.lines[line].len())
+            } else {
+                return None;
+            };
+
+            if moved {
+                self.scroll_to_cursor();
+                state.set_need_redraw(true);
+            }
+
+            return None;
+        };
+
+        if edited {
+            self.scroll_to_cursor();
+            state.set_need_redraw(true);
+            Some(TextAreaViewMessage::Edit)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn text_area_edit_test() {
+    let mut view = TextAreaView::<(), ()>::with_text("abc\ndef");
+    view.set_cursor(0, 2);
+    view.insert_newline();
+    assert_eq!(view.lines(), &["ab", "c", "def"]);
+    assert_eq!(view.cursor(), (1, 0));
+
+    assert!(view.delete_backward());
+    assert_eq!(view.lines(), &["abc", "def"]);
+    assert_eq!(view.cursor(), (0, 2));
+
+    view.set_cursor(0, 3);
+    assert!(view.delete_forward());
+    assert_eq!(view.text(), "abcdef");
+
+    view.insert_char('가');
+    assert_eq!(view.text(), "abc가def");
+    assert_eq!(view.cursor(), (0, 6));
+    assert!(view.move_left());
+    assert_eq!(view.cursor(), (0, 3));
+}
+
+#[test]
+fn text_area_goal_column_test() {
+    let mut view = TextAreaView::<(), ()>::with_text("abcdef\nx\n가나다");
+    view.set_cursor(0, 5);
+    assert!(view.move_line(1));
+    assert_eq!(view.cursor(), (1, 1));
+    assert!(view.move_line(2));
+    // Column 5 is the right half of '다'
+    assert_eq!(view.cursor(), (2, 6));
+}
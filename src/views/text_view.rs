@@ -1,22 +1,38 @@
 use crate::{
+    markup::{
+        self,
+        MarkupError,
+    },
     never::Never,
     printer::Printer,
     style::Style,
+    styled_text::StyledText,
     vec2::Vec2,
     view::View,
 };
-use unicode_width::UnicodeWidthStr;
 
 use std::marker::PhantomData;
 
 pub struct TextView<S, E> {
-    text:       String,
+    text:       StyledText,
     text_width: u16,
     _marker:    PhantomData<(S, E)>,
 }
 
 impl<S, E> TextView<S, E> {
+    #[inline]
     pub fn new(text: impl Into<String>) -> Self {
+        Self::styled(StyledText::plain(text))
+    }
+
+    /// Text written in [`markup`](crate::markup)
+    #[inline]
+    pub fn markup(text: &str) -> Result<Self, MarkupError> {
+        Ok(Self::styled(markup::parse(text)?))
+    }
+
+    /// Text with styled spans
+    pub fn styled(text: impl Into<StyledText>) -> Self {
         let text = text.into();
         let text_width = text.width() as u16;
         Self {
@@ -25,6 +41,11 @@ impl<S, E> TextView<S, E> {
             _marker: PhantomData,
         }
     }
+
+    #[inline]
+    pub fn text(&self) -> &StyledText {
+        &self.text
+    }
 }
 
 impl<S, E> View<S, E> for TextView<S, E> {
@@ -48,7 +69,7 @@ impl<S, E> View<S, E> for TextView<S, E> {
         printer: &mut Printer,
     ) {
         printer.with_style(Style::view(), |printer| {
-            printer.print((0, 0), &self.text);
+            printer.print_styled_text((0, 0), &self.text);
         });
     }
 
@@ -0,0 +1,676 @@
+use crate::{
+    event::{
+        EventLike,
+        KeyEventLike,
+        MouseEventLike,
+    },
+    focus::FocusDirection,
+    printer::Printer,
+    state::RedrawState,
+    style::Style,
+    vec2::Vec2,
+    view::View,
+};
+use std::marker::PhantomData;
+use unicode_width::UnicodeWidthStr;
+
+const GUIDE: &str = "│ ";
+const BRANCH: &str = "├─";
+const LAST_BRANCH: &str = "└─";
+const EXPANDED: &str = "▾ ";
+const COLLAPSED: &str = "▸ ";
+const LEAF: &str = "─ ";
+const ROOT_LEAF: &str = "  ";
+
+/// Loads children of a node when it is expanded for the first time
+///
+/// Each child is `(label, value, lazy)`, `lazy` children are loaded again when they are expanded.
+type Loader<T> = Box<dyn FnMut(&T) -> Vec<(String, T, bool)>>;
+
+struct TreeNode<T> {
+    label:    String,
+    value:    T,
+    parent:   Option<usize>,
+    children: Vec<usize>,
+    expanded: bool,
+    /// Children are not loaded yet
+    lazy:     bool,
+}
+
+impl<T> TreeNode<T> {
+    #[inline]
+    fn expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+/// Visible node with its guides
+struct Row {
+    id:     usize,
+    /// Guides and marker before label
+    prefix: String,
+    /// Width of prefix and label
+    width:  usize,
+}
+
+/// Tree of labeled values, nodes are identified by id returned from [`TreeView::insert`]
+pub struct TreeView<S, E, T> {
+    nodes:    Vec<TreeNode<T>>,
+    roots:    Vec<usize>,
+    /// Visible nodes in display order
+    rows:     Vec<Row>,
+    selected: usize,
+    scroll:   usize,
+    height:   u16,
+    loader:   Option<Loader<T>>,
+    focused:  bool,
+    _marker:  PhantomData<(S, E)>,
+}
+
+impl<S: RedrawState, E, T> Default for TreeView<S, E, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: RedrawState, E, T> TreeView<S, E, T> {
+    pub fn new() -> Self {
+        Self {
+            nodes:    Vec::new(),
+            roots:    Vec::new(),
+            rows:     Vec::new(),
+            selected: 0,
+            scroll:   0,
+            height:   0,
+            loader:   None,
+            focused:  false,
+            _marker:  PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn loader(
+        mut self,
+        loader: impl FnMut(&T) -> Vec<(String, T, bool)> + 'static,
+    ) -> Self {
+        self.set_loader(loader);
+        self
+    }
+
+    /// See [`TreeView::insert_lazy`]
+    #[inline]
+    pub fn set_loader(
+        &mut self,
+        loader: impl FnMut(&T) -> Vec<(String, T, bool)> + 'static,
+    ) {
+        self.loader = Some(Box::new(loader));
+    }
+
+    /// Add a node under `parent` or as a root, returns id of new node
+    #[inline]
+    pub fn insert(
+        &mut self,
+        parent: Option<usize>,
+        label: impl Into<String>,
+        value: T,
+    ) -> usize {
+        let id = self.insert_node(parent, label.into(), value, false);
+        self.add_row(id);
+        id
+    }
+
+    /// Add a node whose children are loaded by loader when it is expanded
+    #[inline]
+    pub fn insert_lazy(
+        &mut self,
+        parent: Option<usize>,
+        label: impl Into<String>,
+        value: T,
+    ) -> usize {
+        let id = self.insert_node(parent, label.into(), value, true);
+        self.add_row(id);
+        id
+    }
+
+    fn insert_node(
+        &mut self,
+        parent: Option<usize>,
+        label: String,
+        value: T,
+        lazy: bool,
+    ) -> usize {
+        let id = self.nodes.len();
+        match parent {
+            Some(parent) => {
+                let parent = &mut self.nodes[parent];
+                parent.children.push(id);
+                parent.lazy = false;
+            }
+            None => self.roots.push(id),
+        }
+
+        self.nodes.push(TreeNode {
+            label,
+            value,
+            parent,
+            children: Vec::new(),
+            expanded: false,
+            lazy,
+        });
+
+        id
+    }
+
+    /// Update rows for newly inserted node `id` without rebuilding all of them
+    fn add_row(
+        &mut self,
+        id: usize,
+    ) {
+        let parent = match self.nodes[id].parent {
+            Some(parent) => parent,
+            None => {
+                let row = self.make_row(id);
+                self.rows.push(row);
+                return;
+            }
+        };
+
+        if !self.is_visible(parent) {
+            return;
+        }
+
+        let parent_row = match self.row_of(parent) {
+            Some(row) => row,
+            None => return,
+        };
+
+        // Marker of parent may change
+        self.rows[parent_row] = self.make_row(parent);
+
+        if !self.nodes[parent].expanded {
+            return;
+        }
+
+        let siblings = &self.nodes[parent].children;
+        let mut pos = parent_row + 1;
+
+        // Previous last sibling and its visible descendants get `├─` and `│ ` now
+        if let Some(prev) = siblings.len().checked_sub(2).map(|i| siblings[i]) {
+            let mut last = prev;
+            while let Some(&child) = self.nodes[last].children.last() {
+                if !self.nodes[last].expanded {
+                    break;
+                }
+                last = child;
+            }
+
+            if let (Some(start), Some(end)) = (self.row_of(prev), self.row_of(last)) {
+                for row in start..=end {
+                    self.rows[row] = self.make_row(self.rows[row].id);
+                }
+                pos = end + 1;
+            }
+        }
+
+        let row = self.make_row(id);
+        self.rows.insert(pos, row);
+
+        if pos <= self.selected && self.rows.len() > 1 {
+            self.selected += 1;
+        }
+        self.scroll_to_selected();
+    }
+
+    #[inline]
+    pub fn label(
+        &self,
+        id: usize,
+    ) -> Option<&str> {
+        self.nodes.get(id).map(|node| node.label.as_str())
+    }
+
+    #[inline]
+    pub fn value(
+        &self,
+        id: usize,
+    ) -> Option<&T> {
+        self.nodes.get(id).map(|node| &node.value)
+    }
+
+    #[inline]
+    pub fn value_mut(
+        &mut self,
+        id: usize,
+    ) -> Option<&mut T> {
+        self.nodes.get_mut(id).map(|node| &mut node.value)
+    }
+
+    #[inline]
+    pub fn parent(
+        &self,
+        id: usize,
+    ) -> Option<usize> {
+        self.nodes.get(id)?.parent
+    }
+
+    #[inline]
+    pub fn children(
+        &self,
+        id: usize,
+    ) -> &[usize] {
+        self.nodes
+            .get(id)
+            .map_or(&[], |node| node.children.as_slice())
+    }
+
+    #[inline]
+    pub fn is_expanded(
+        &self,
+        id: usize,
+    ) -> bool {
+        self.nodes.get(id).is_some_and(|node| node.expanded)
+    }
+
+    /// Id of selected node
+    #[inline]
+    pub fn selected(&self) -> Option<usize> {
+        self.rows.get(self.selected).map(|row| row.id)
+    }
+
+    #[inline]
+    pub fn selected_val(&self) -> Option<&T> {
+        self.value(self.selected()?)
+    }
+
+    /// Expand node, loading its children if needed. Returns `false` when nothing is changed
+    pub fn expand(
+        &mut self,
+        id: usize,
+    ) -> bool {
+        let lazy = self.nodes[id].lazy;
+        if lazy {
+            self.load(id);
+        }
+
+        let node = &mut self.nodes[id];
+
+        if node.expanded || node.children.is_empty() {
+            // Marker is changed when nothing is loaded
+            if lazy {
+                self.rebuild_rows();
+            }
+            return false;
+        }
+
+        node.expanded = true;
+        self.rebuild_rows();
+        true
+    }
+
+    /// Collapse node, returns `false` when nothing is changed
+    pub fn collapse(
+        &mut self,
+        id: usize,
+    ) -> bool {
+        let node = &mut self.nodes[id];
+
+        if !node.expanded {
+            return false;
+        }
+
+        node.expanded = false;
+        self.rebuild_rows();
+        true
+    }
+
+    pub fn toggle(
+        &mut self,
+        id: usize,
+    ) -> bool {
+        if self.nodes[id].expanded {
+            self.collapse(id)
+        } else {
+            self.expand(id)
+        }
+    }
+
+    fn load(
+        &mut self,
+        id: usize,
+    ) {
+        self.nodes[id].lazy = false;
+
+        let children = match self.loader.as_mut() {
+            Some(loader) => loader(&self.nodes[id].value),
+            None => return,
+        };
+
+        for (label, value, lazy) in children {
+            self.insert_node(Some(id), label, value, lazy);
+        }
+    }
+
+    /// Select row at `row`
+    pub fn select(
+        &mut self,
+        state: &mut S,
+        row: usize,
+    ) -> Option<TreeViewMessage> {
+        let row = row.min(self.rows.len().saturating_sub(1));
+
+        if row == self.selected {
+            None
+        } else {
+            self.selected = row;
+            self.scroll_to_selected();
+            state.set_need_redraw(true);
+            Some(TreeViewMessage::IndexChanged)
+        }
+    }
+
+    /// Select node by id, its ancestors are expanded
+    pub fn select_node(
+        &mut self,
+        state: &mut S,
+        id: usize,
+    ) -> Option<TreeViewMessage> {
+        let mut parent = self.nodes[id].parent;
+        while let Some(p) = parent {
+            self.nodes[p].expanded = true;
+            parent = self.nodes[p].parent;
+        }
+        self.rebuild_rows();
+
+        let row = self.row_of(id)?;
+        self.select(state, row)
+    }
+
+    fn rebuild_rows(&mut self) {
+        let selected = self.selected();
+
+        self.rows.clear();
+        let mut stack: Vec<usize> = self.roots.iter().rev().copied().collect();
+
+        while let Some(id) = stack.pop() {
+            let row = self.make_row(id);
+            self.rows.push(row);
+
+            let node = &self.nodes[id];
+            if node.expanded {
+                stack.extend(node.children.iter().rev());
+            }
+        }
+
+        // Keep selected node or its nearest visible ancestor
+        let mut target = selected;
+        while let Some(id) = target {
+            if let Some(row) = self.row_of(id) {
+                self.selected = row;
+                break;
+            }
+            target = self.nodes[id].parent;
+        }
+
+        self.scroll_to_selected();
+    }
+
+    fn make_row(
+        &self,
+        id: usize,
+    ) -> Row {
+        let prefix = self.prefix(id);
+        let width = prefix.width() + self.nodes[id].label.width();
+
+        Row { id, prefix, width }
+    }
+
+    /// Row of node `id`, searched from the end as nodes are usually added there
+    #[inline]
+    fn row_of(
+        &self,
+        id: usize,
+    ) -> Option<usize> {
+        self.rows.iter().rposition(|row| row.id == id)
+    }
+
+    /// All ancestors of `id` are expanded
+    fn is_visible(
+        &self,
+        id: usize,
+    ) -> bool {
+        let mut parent = self.nodes[id].parent;
+        while let Some(p) = parent {
+            if !self.nodes[p].expanded {
+                return false;
+            }
+            parent = self.nodes[p].parent;
+        }
+
+        true
+    }
+
+    #[inline]
+    fn page(&self) -> usize {
+        self.height.max(1) as usize
+    }
+
+    fn scroll_to_selected(&mut self) {
+        let page = self.page();
+
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + page {
+            self.scroll = self.selected + 1 - page;
+        }
+    }
+
+    fn is_last(
+        &self,
+        id: usize,
+    ) -> bool {
+        let siblings = match self.nodes[id].parent {
+            Some(parent) => &self.nodes[parent].children,
+            None => &self.roots,
+        };
+
+        siblings.last() == Some(&id)
+    }
+
+    /// Guides and marker before label
+    fn prefix(
+        &self,
+        id: usize,
+    ) -> String {
+        let node = &self.nodes[id];
+        let mut prefix = String::new();
+
+        // Guides of ancestors below root, from the outermost
+        let mut guides = Vec::new();
+        let mut parent = node.parent;
+        while let Some(p) = parent {
+            if self.nodes[p].parent.is_some() {
+                guides.push(if self.is_last(p) { "  " } else { GUIDE });
+            }
+            parent = self.nodes[p].parent;
+        }
+        guides.iter().rev().for_each(|guide| prefix.push_str(guide));
+
+        if node.parent.is_some() {
+            prefix.push_str(if self.is_last(id) {
+                LAST_BRANCH
+            } else {
+                BRANCH
+            });
+        }
+
+        prefix.push_str(if node.expanded {
+            EXPANDED
+        } else if node.expandable() {
+            COLLAPSED
+        } else if node.parent.is_some() {
+            LEAF
+        } else {
+            ROOT_LEAF
+        });
+
+        prefix
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreeViewMessage {
+    Select,
+    IndexChanged,
+    Expanded,
+    Collapsed,
+}
+
+impl<S: RedrawState, E: EventLike, T> View<S, E> for TreeView<S, E, T> {
+    type Message = TreeViewMessage;
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        let width = printer.bound().w() as usize;
+        let highlight = if self.focused {
+            Style::highlight()
+        } else {
+            Style::highlight_inactive()
+        };
+
+        printer.with_style(Style::view(), |printer| {
+            let visible = self.rows.iter().skip(self.scroll).take(self.page());
+
+            for (y, (index, row)) in (0..).zip((self.scroll..).zip(visible)) {
+                let label = &self.nodes[row.id].label;
+                let x = row.prefix.width() as u16;
+                printer.print((0, y), &row.prefix);
+
+                if index == self.selected {
+                    printer.with_style(highlight, |printer| {
+                        printer.print((x, y), &" ".repeat(width.saturating_sub(x as usize)));
+                        printer.print((x, y), label);
+                    });
+                } else {
+                    printer.print((x, y), label);
+                }
+            }
+        });
+    }
+
+    fn layout(
+        &mut self,
+        size: Vec2,
+    ) {
+        self.height = size.y;
+
+        let max_scroll = self.rows.len().saturating_sub(self.page());
+        self.scroll = self.scroll.min(max_scroll);
+        self.scroll_to_selected();
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        let width = self.rows.iter().map(|row| row.width).max().unwrap_or(0);
+
+        Vec2::new(width as u16, self.rows.len() as u16)
+    }
+
+    #[inline]
+    fn take_focus(
+        &mut self,
+        _direction: FocusDirection,
+    ) -> bool {
+        self.focused = !self.focused;
+        self.focused
+    }
+
+    #[inline]
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    #[inline]
+    fn release_focus(&mut self) {
+        self.focused = false;
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut S,
+        e: E,
+    ) -> Option<Self::Message> {
+        if let Some(me) = e.try_mouse() {
+            if let Some(pos) = me.try_left_down() {
+                let row = self.scroll + pos.y as usize;
+                let id = self.rows.get(row)?.id;
+                let selected = self.select(state, row);
+
+                if self.nodes[id].expandable() {
+                    let node = &self.nodes[id];
+                    let (expanded, lazy) = (node.expanded, node.lazy);
+
+                    if self.toggle(id) {
+                        state.set_need_redraw(true);
+
+                        return Some(if expanded {
+                            TreeViewMessage::Collapsed
+                        } else {
+                            TreeViewMessage::Expanded
+                        });
+                    } else if lazy {
+                        // Marker is changed when nothing is loaded
+                        state.set_need_redraw(true);
+                    }
+                }
+
+                return selected.or(Some(TreeViewMessage::Select));
+            } else if me.try_scroll_up().is_some() {
+                return self.select(state, self.selected.saturating_sub(1));
+            } else if me.try_scroll_down().is_some() {
+                return self.select(state, self.selected + 1);
+            }
+
+            return None;
+        }
+
+        let ke = e.try_key()?;
+        let id = self.selected()?;
+
+        if ke.try_enter() {
+            Some(TreeViewMessage::Select)
+        } else if ke.try_right() {
+            if self.expand(id) {
+                state.set_need_redraw(true);
+                Some(TreeViewMessage::Expanded)
+            } else if self.nodes[id].expanded {
+                self.select(state, self.selected + 1)
+            } else {
+                None
+            }
+        } else if ke.try_left() {
+            if self.collapse(id) {
+                state.set_need_redraw(true);
+                Some(TreeViewMessage::Collapsed)
+            } else {
+                let parent = self.nodes[id].parent?;
+                self.select_node(state, parent)
+            }
+        } else if ke.try_up() {
+            self.select(state, self.selected.saturating_sub(1))
+        } else if ke.try_down() {
+            self.select(state, self.selected + 1)
+        } else if ke.try_home() {
+            self.select(state, 0)
+        } else if ke.try_end() {
+            self.select(state, self.rows.len())
+        } else if ke.try_page_up() {
+            self.select(state, self.selected.saturating_sub(self.page()))
+        } else if ke.try_page_down() {
+            self.select(state, self.selected + self.page())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Helpers shared by integration tests
+
+#![allow(dead_code)]
+
+use turi::{
+    event::{
+        Event,
+        MouseButton,
+        MouseEvent,
+        MouseEventKind,
+    },
+    vec2::Vec2,
+    view::View,
+    views::{
+        ButtonDecoration,
+        ButtonView,
+        TextView,
+    },
+};
+
+/// Text which returns `false` on any message
+pub fn text(text: &str) -> impl View<bool, Event, Message = bool> {
+    TextView::new(text).map(|_, _, _| false)
+}
+
+/// Button which returns `true` when it is pressed
+pub fn button(text: &str) -> impl View<bool, Event, Message = bool> {
+    ButtonView::new(text, ButtonDecoration::NoDecoration).map(|_, _, _| true)
+}
+
+pub fn mouse(
+    kind: MouseEventKind,
+    x: u16,
+    y: u16,
+) -> Event {
+    Event::Mouse(MouseEvent::new(kind, (x, y)))
+}
+
+/// Left button down at `(x, y)`
+pub fn click(
+    x: u16,
+    y: u16,
+) -> Event {
+    mouse(MouseEventKind::Down(MouseButton::Left), x, y)
+}
+
+/// Lay out `view` in `size` then collect messages of `events`
+pub fn send<V: View<bool, Event>>(
+    view: &mut V,
+    size: impl Into<Vec2>,
+    events: Vec<Event>,
+) -> Vec<V::Message> {
+    let mut state = false;
+    view.layout(size.into());
+    events
+        .into_iter()
+        .filter_map(|e| view.on_event(&mut state, e))
+        .collect()
+}
@@ -0,0 +1,218 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifier,
+        MouseButton,
+        MouseEventKind,
+    },
+    executor,
+    view::View,
+    views::{
+        DialogView,
+        EditView,
+    },
+};
+
+mod common;
+
+use common::mouse;
+
+fn chars(text: &str) -> impl Iterator<Item = Event> + '_ {
+    text.chars().map(|ch| Event::from(KeyCode::Char(ch)))
+}
+
+fn shift(code: KeyCode) -> Event {
+    Event::from(KeyEvent::new(code, KeyModifier::Shift))
+}
+
+fn ctrl(code: KeyCode) -> Event {
+    Event::from(KeyEvent::new(code, KeyModifier::Control))
+}
+
+#[test]
+fn edit_view_insert_at_cursor() {
+    let events: Vec<_> = chars("ac")
+        .chain(vec![Event::from(KeyCode::Left)])
+        .chain(chars("b"))
+        .chain(vec![
+            Event::from(KeyCode::Home),
+            Event::from(KeyCode::Delete),
+        ])
+        .collect();
+
+    executor::test_styled(&mut EditView::new(), events, (4, 1).into(), |lines| {
+        assert_eq!(lines, &["[reverse,white]b[/][white]c[/]  "]);
+    });
+}
+
+#[test]
+fn edit_view_shift_select() {
+    let events: Vec<_> = chars("abcd")
+        .chain(vec![shift(KeyCode::Left), shift(KeyCode::Left)])
+        .collect();
+
+    executor::test_styled(&mut EditView::new(), events, (5, 1).into(), |lines| {
+        assert_eq!(lines, &[
+            "[white]ab[/][reverse,white]c[/][reverse,yellow]d[/] "
+        ]);
+    });
+}
+
+#[test]
+fn edit_view_replace_selection() {
+    let events: Vec<_> = chars("foo bar")
+        .chain(vec![Event::from(KeyEvent::new(
+            KeyCode::Left,
+            KeyModifier::Control | KeyModifier::Shift,
+        ))])
+        .chain(chars("x"))
+        .collect();
+
+    executor::test(&mut EditView::new(), events, (8, 1).into(), |lines| {
+        assert_eq!(lines, &["foo x   "]);
+    });
+}
+
+#[test]
+fn edit_view_undo_redo() {
+    let events: Vec<_> = chars("foo")
+        .chain(vec![ctrl(KeyCode::Left)])
+        .chain(chars("bar "))
+        .chain(vec![
+            Event::from(KeyEvent::new(KeyCode::Char('z'), KeyModifier::Control)),
+            Event::from(KeyEvent::new(KeyCode::Char('z'), KeyModifier::Control)),
+            Event::from(KeyEvent::new(KeyCode::Char('y'), KeyModifier::Control)),
+        ])
+        .collect();
+
+    executor::test(&mut EditView::new(), events, (8, 1).into(), |lines| {
+        assert_eq!(lines, &["foo     "]);
+    });
+}
+
+#[test]
+fn edit_view_horizontal_scroll() {
+    let events: Vec<_> = chars("123456")
+        .chain(vec![Event::from(KeyCode::Home), Event::from(KeyCode::End)])
+        .collect();
+
+    executor::test(&mut EditView::new(), events, (4, 1).into(), |lines| {
+        assert_eq!(lines, &["456 "]);
+    });
+}
+
+#[test]
+fn edit_view_horizontal_scroll_home() {
+    let events: Vec<_> = chars("123456")
+        .chain(vec![Event::from(KeyCode::Home)])
+        .collect();
+
+    executor::test(&mut EditView::new(), events, (4, 1).into(), |lines| {
+        assert_eq!(lines, &["1234"]);
+    });
+}
+
+#[test]
+fn edit_view_mouse_drag_select() {
+    let events: Vec<_> = chars("abcdef")
+        .chain(vec![
+            mouse(MouseEventKind::Down(MouseButton::Left), 1, 0),
+            mouse(MouseEventKind::Drag(MouseButton::Left), 4, 0),
+        ])
+        .collect();
+
+    executor::test_styled(&mut EditView::new(), events, (7, 1).into(), |lines| {
+        assert_eq!(lines, &[
+            "[white]a[/][reverse,yellow]bcd[/][reverse,white]e[/][white]f[/] "
+        ]);
+    });
+}
+
+#[test]
+fn edit_view_password() {
+    let mut view = EditView::new().password(true);
+
+    executor::test(&mut view, chars("가b"), (5, 1).into(), |lines| {
+        assert_eq!(lines, &["**   "]);
+    });
+
+    assert_eq!(view.text(), "가b");
+}
+
+#[test]
+fn edit_view_password_masks_clusters() {
+    let mut view = EditView::new().password(true);
+
+    executor::test(
+        &mut view,
+        chars("e\u{301}🇰🇷"),
+        (5, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["**   "]);
+        },
+    );
+}
+
+#[test]
+fn edit_view_placeholder() {
+    executor::test_styled(
+        &mut EditView::new().placeholder("name"),
+        Vec::<Event>::new(),
+        (5, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["[reverse,fixed(8)]n[/][fixed(8)]ame[/] "]);
+        },
+    );
+}
+
+#[test]
+fn edit_view_filter_max_len() {
+    executor::test(
+        &mut EditView::new().filter(|ch| ch.is_ascii_digit()).max_len(4),
+        chars("1a2b3c456"),
+        (6, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["1234  "]);
+        },
+    );
+}
+
+#[test]
+fn edit_view_max_len_counts_clusters() {
+    let mut view = EditView::new().max_len(2);
+
+    executor::test(&mut view, chars("e\u{301}ab"), (4, 1).into(), |_| {});
+
+    assert_eq!(view.text(), "e\u{301}a");
+}
+
+#[test]
+fn dialog_show_edit_error() {
+    let edit = EditView::new()
+        .validator(|text| {
+            if text.parse::<u8>().is_ok() {
+                Ok(())
+            } else {
+                Err("NaN".into())
+            }
+        })
+        .map(|_, _, _| false);
+
+    let mut dialog = DialogView::new(edit).map(|dialog, _, msg| {
+        let error = dialog.content().error().map(String::from);
+        dialog.set_error(error);
+        msg
+    });
+
+    executor::test_styled(&mut dialog, chars("1x"), (5, 4).into(), |lines| {
+        assert_eq!(lines, &[
+            "[white]┌───┐[/]",
+            "[white]│[/][red]1x[/][reverse,red] [/][white]│[/]",
+            "[white]│[/]   [white]│[/]",
+            "[red]NaN[/][white]─┘[/]",
+        ]);
+    });
+}
@@ -0,0 +1,114 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifier,
+    },
+    executor,
+    view::View,
+    views::{
+        DialogView,
+        EditView,
+        LinearView,
+    },
+};
+
+mod common;
+
+use common::{
+    button,
+    click,
+    text,
+};
+
+fn tree() -> LinearView<bool, Event, bool> {
+    LinearView::vertical()
+        .child(button("a"))
+        .child(
+            LinearView::horizontal()
+                .child(button("b"))
+                .child(button("c")),
+        )
+        .child(DialogView::new(EditView::new().map(|_, _, _| false)).button("ok", |_| true))
+}
+
+fn tab() -> Event {
+    Event::from(KeyCode::Tab)
+}
+
+fn back_tab() -> Event {
+    Event::from(KeyEvent::new(KeyCode::BackTab, KeyModifier::Shift))
+}
+
+#[test]
+fn initial_focus() {
+    executor::test_styled(&mut tree(), vec![], (6, 6).into(), |lines| {
+        assert_eq!(&lines[..2], &[
+            "[reverse,yellow]a[/]     ",
+            "[white]bc[/]    ",
+        ]);
+    });
+}
+
+#[test]
+fn tab_walks_nested_views() {
+    executor::test_styled(&mut tree(), vec![tab(), tab()], (6, 6).into(), |lines| {
+        assert_eq!(&lines[..2], &[
+            "[white]a[/]     ",
+            "[white]b[/][reverse,yellow]c[/]    ",
+        ]);
+    });
+}
+
+#[test]
+fn tab_into_dialog_buttons() {
+    executor::test_styled(
+        &mut tree(),
+        vec![tab(), tab(), tab(), tab()],
+        (6, 6).into(),
+        |lines| {
+            assert_eq!(&lines[4], "[white]│[/][reverse,yellow]<ok>[/][white]│[/]");
+        },
+    );
+}
+
+#[test]
+fn tab_wraps_around() {
+    executor::test_styled(
+        &mut tree(),
+        vec![tab(), tab(), tab(), tab(), tab()],
+        (6, 6).into(),
+        |lines| {
+            assert_eq!(&lines[0], "[reverse,yellow]a[/]     ");
+        },
+    );
+}
+
+#[test]
+fn back_tab_wraps_around() {
+    executor::test_styled(&mut tree(), vec![back_tab()], (6, 6).into(), |lines| {
+        assert_eq!(&lines[0], "[white]a[/]     ");
+        assert_eq!(&lines[4], "[white]│[/][reverse,yellow]<ok>[/][white]│[/]");
+    });
+}
+
+#[test]
+fn click_takes_focus() {
+    executor::test_styled(&mut tree(), vec![click(1, 1)], (6, 6).into(), |lines| {
+        assert_eq!(&lines[..2], &[
+            "[white]a[/]     ",
+            "[white]b[/][reverse,yellow]c[/]    ",
+        ]);
+    });
+}
+
+#[test]
+fn click_keeps_focus_on_unfocusable_child() {
+    let mut view = LinearView::vertical().child(button("a")).child(text("x"));
+
+    executor::test_styled(&mut view, vec![click(0, 1)], (2, 2).into(), |lines| {
+        assert_eq!(lines, &["[reverse,yellow]a[/] ", "[white]x[/] "]);
+    });
+}
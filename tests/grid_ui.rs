@@ -0,0 +1,124 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+    },
+    executor,
+    layout::Constraint,
+    view::View,
+    views::GridView,
+};
+
+mod common;
+
+use common::{
+    button,
+    click,
+    send,
+    text,
+};
+
+#[test]
+fn grid_form_columns_line_up() {
+    let mut view = GridView::new()
+        .gap(0, 1)
+        .child(0, 0, text("Name"))
+        .child(0, 1, text("kim"))
+        .child(1, 0, text("Id"))
+        .child(1, 1, text("42"));
+
+    assert_eq!(view.desired_size(), (8, 2).into());
+
+    executor::test(&mut view, Vec::<Event>::new(), (10, 2).into(), |lines| {
+        assert_eq!(lines, &["Name kim  ", "Id   42   "]);
+    });
+}
+
+#[test]
+fn grid_track_sizing() {
+    let mut view = GridView::new()
+        .columns([
+            Constraint::Fixed(2),
+            Constraint::Fill(1),
+            Constraint::Fill(2),
+        ])
+        .rows([Constraint::Auto, Constraint::Fixed(2)])
+        .child(0, 0, text("abcd"))
+        .child(0, 1, text("efgh"))
+        .child(0, 2, text("ijkl"))
+        .child_span(1, 0, 1, 3, text("wide wide wide"));
+
+    executor::test(&mut view, Vec::<Event>::new(), (11, 4).into(), |lines| {
+        assert_eq!(lines, &[
+            "abefgijkl  ",
+            "wide wide w",
+            "           ",
+            "           "
+        ]);
+    });
+}
+
+#[test]
+fn grid_span_grows_tracks() {
+    let view = GridView::<bool, Event, bool>::new()
+        .gap(1, 1)
+        .child(0, 0, text("a"))
+        .child(0, 1, text("b"))
+        .child_span(1, 0, 1, 2, text("abcdef"));
+
+    // Missing width goes to last spanned column
+    assert_eq!(view.desired_size(), (6, 3).into());
+}
+
+fn buttons() -> GridView<bool, Event, bool> {
+    GridView::new()
+        .gap(1, 1)
+        .child(1, 1, button("d"))
+        .child(0, 0, button("a"))
+        .child(0, 1, button("b"))
+        .child(1, 0, button("c"))
+}
+
+#[test]
+fn grid_mouse_routing() {
+    let mut view = buttons();
+    let mut state = false;
+
+    view.layout((3, 3).into());
+
+    // Gap isn't routed to any child
+    assert_eq!(view.on_event(&mut state, click(1, 0)), None);
+    assert_eq!(view.on_event(&mut state, click(2, 2)), Some(true));
+}
+
+#[test]
+fn grid_keys_go_to_focused_child() {
+    let mut view = GridView::new().child(0, 1, button("b"));
+    let enter = || Event::from(KeyCode::Enter);
+
+    send(&mut view, (2, 1), vec![Event::from(KeyCode::Tab)]);
+    view.add_child(0, 0, text("a"));
+
+    // Text doesn't take focus from the button
+    assert_eq!(
+        send(&mut view, (2, 1), vec![enter(), click(0, 0), enter()]),
+        [true, true]
+    );
+}
+
+#[test]
+fn grid_focus_reading_order() {
+    executor::test_styled(
+        &mut buttons(),
+        vec![Event::from(KeyCode::Tab)],
+        (3, 3).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "[white]a[/] [reverse,yellow]b[/]",
+                "   ",
+                "[white]c[/] [white]d[/]"
+            ]);
+        },
+    );
+}
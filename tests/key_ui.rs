@@ -0,0 +1,87 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifier,
+    },
+    executor,
+    orientation::Orientation,
+    view::View,
+    views::{
+        EditView,
+        SelectView,
+        TextView,
+    },
+};
+
+fn chars(text: &str) -> impl Iterator<Item = Event> + '_ {
+    text.chars().map(|ch| Event::from(KeyCode::Char(ch)))
+}
+
+#[test]
+fn select_view_end_home() {
+    let items = (0..5).map(|i| (i.to_string(), i));
+
+    executor::test_styled(
+        &mut SelectView::with_items(items),
+        vec![
+            Event::from(KeyCode::End),
+            Event::from(KeyCode::Home),
+            Event::from(KeyCode::PageDown),
+        ],
+        (1, 5).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "[white]0[/]",
+                "[white]1[/]",
+                "[white]2[/]",
+                "[white]3[/]",
+                "[reverse,yellow]4[/]",
+            ]);
+        },
+    )
+}
+
+#[test]
+fn edit_view_delete_word() {
+    executor::test(
+        &mut EditView::new(),
+        chars("foo bar  ").chain(vec![Event::from(KeyEvent::new(
+            KeyCode::Char('w'),
+            KeyModifier::Control,
+        ))]),
+        (8, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["foo     "]);
+        },
+    )
+}
+
+#[test]
+fn edit_view_shift_char() {
+    executor::test(
+        &mut EditView::new(),
+        vec![Event::from(KeyEvent::new(
+            KeyCode::Char('A'),
+            KeyModifier::Shift,
+        ))],
+        (2, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["A "]);
+        },
+    )
+}
+
+#[test]
+fn horizontal_scroll_key_end() {
+    executor::test(
+        &mut TextView::new("123456").scrollable(Orientation::Horizontal),
+        vec![Event::from(KeyCode::End)],
+        (4, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["3456", "──░░",]);
+        },
+    )
+}
@@ -0,0 +1,107 @@
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifier,
+    },
+    keymap::KeyMap,
+    view::View,
+    views::{
+        ButtonDecoration,
+        ButtonView,
+        EditView,
+        EditViewMessage,
+        LinearView,
+        TextView,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Msg {
+    Top,
+    Save,
+    Quit,
+    Edit,
+}
+
+fn key(ch: char) -> Event {
+    Event::from(KeyCode::Char(ch))
+}
+
+fn ctrl(ch: char) -> Event {
+    Event::from(KeyEvent::new(KeyCode::Char(ch), KeyModifier::Control))
+}
+
+fn text() -> impl View<bool, Event, Message = Msg> {
+    TextView::new("text").map(|_, _, never| match never {})
+}
+
+#[test]
+fn keymap_chord() {
+    let mut view = text().keymap(
+        KeyMap::new()
+            .bind("g g", Msg::Top)
+            .bind("ctrl-x ctrl-s", Msg::Save),
+    );
+    let mut state = false;
+
+    assert_eq!(view.on_event(&mut state, key('g')), None);
+    assert_eq!(view.on_event(&mut state, key('g')), Some(Msg::Top));
+
+    assert_eq!(view.on_event(&mut state, ctrl('x')), None);
+    assert_eq!(view.on_event(&mut state, ctrl('s')), Some(Msg::Save));
+
+    // broken chord starts again
+    assert_eq!(view.on_event(&mut state, key('g')), None);
+    assert_eq!(view.on_event(&mut state, ctrl('x')), None);
+    assert_eq!(view.on_event(&mut state, ctrl('s')), Some(Msg::Save));
+}
+
+#[test]
+fn keymap_scope_fallthrough() {
+    let edit = EditView::new()
+        .map(|_, _, msg| {
+            match msg {
+                EditViewMessage::Edit | EditViewMessage::Submit => Msg::Edit,
+            }
+        })
+        .keymap(KeyMap::new().bind("ctrl-s", Msg::Save));
+
+    let mut view = LinearView::vertical().child(edit).focus(0).keymap(
+        KeyMap::new()
+            .bind("ctrl-s", Msg::Quit)
+            .bind("ctrl-q", Msg::Quit)
+            .bind("q", Msg::Quit),
+    );
+    let mut state = false;
+
+    // inner scope wins
+    assert_eq!(view.on_event(&mut state, ctrl('s')), Some(Msg::Save));
+    // unbound in inner scope falls through to parent
+    assert_eq!(view.on_event(&mut state, ctrl('q')), Some(Msg::Quit));
+    // focused EditView consumes chars
+    assert_eq!(view.on_event(&mut state, key('q')), Some(Msg::Edit));
+}
+
+#[test]
+fn keymap_inner_chord_pending() {
+    let inner = ButtonView::new("ok", ButtonDecoration::NoDecoration)
+        .map(|_, _, _| Msg::Edit)
+        .keymap(KeyMap::new().bind("g g", Msg::Top));
+    let mut view = LinearView::vertical()
+        .child(inner)
+        .focus(0)
+        .keymap(KeyMap::new().bind("g", Msg::Quit).bind("q", Msg::Quit));
+    let mut state = false;
+
+    // inner scope waits for second `g`
+    assert_eq!(view.on_event(&mut state, key('g')), None);
+    assert!(view.has_pending_keys());
+    assert_eq!(view.on_event(&mut state, key('g')), Some(Msg::Top));
+    assert!(!view.has_pending_keys());
+
+    // outer scope gets keys which break the inner chord
+    assert_eq!(view.on_event(&mut state, key('g')), None);
+    assert_eq!(view.on_event(&mut state, key('q')), Some(Msg::Quit));
+}
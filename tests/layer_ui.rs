@@ -0,0 +1,115 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::Event,
+    executor,
+    rect::Rect,
+    view::View,
+    views::{
+        DialogView,
+        LayeredView,
+        Placement,
+    },
+};
+
+mod common;
+
+use common::{
+    button,
+    click,
+    send,
+    text,
+};
+
+#[test]
+fn layer_placements() {
+    let mut view = LayeredView::new()
+        .layer_placed(Placement::Fullscreen, text("........"))
+        .layer_placed(Placement::TopRight, text("tr"))
+        .layer_placed(Placement::BottomLeft, text("bl"))
+        .layer_placed(Placement::BottomRight, text("br"))
+        .layer_placed(Placement::Center, text("ce"))
+        .layer_at((1, 0), text("ab"));
+
+    executor::test(&mut view, Vec::<Event>::new(), (8, 4).into(), |lines| {
+        assert_eq!(lines, &[".ab...tr", "   ce   ", "        ", "bl    br"]);
+    });
+}
+
+#[test]
+fn centered_dialog() {
+    let mut view = LayeredView::new()
+        .layer(text("base"))
+        .layer_placed(Placement::Center, DialogView::new(text("hi")).title("T"));
+
+    executor::test(&mut view, Vec::<Event>::new(), (8, 5).into(), |lines| {
+        assert_eq!(lines, &[
+            "baT──┐  ",
+            "  │hi│  ",
+            "  │  │  ",
+            "  └──┘  ",
+            "        ",
+        ]);
+    });
+}
+
+#[test]
+fn modal_dims_backdrop() {
+    let mut view = LayeredView::new()
+        .layer(text("base"))
+        .modal(Placement::BottomRight, text("ok"));
+
+    executor::test_styled(&mut view, Vec::<Event>::new(), (6, 2).into(), |lines| {
+        assert_eq!(lines, &["[dim,white]base[/]  ", "    [white]ok[/]"]);
+    });
+}
+
+#[test]
+fn layer_mouse_offset() {
+    let mut view = LayeredView::new()
+        .layer(button("base"))
+        .layer_placed(Placement::Center, button("ok"));
+
+    // Center layer is at (3, 1)
+    assert_eq!(
+        send(&mut view, (8, 4), vec![
+            click(2, 1),
+            click(3, 1),
+            click(4, 1)
+        ]),
+        [true, true]
+    );
+    assert_eq!(
+        view.layer_rect(1).map(|rect| rect.start()),
+        Some((3, 1).into())
+    );
+
+    // Click outside of top layer goes to layer under cursor
+    assert_eq!(send(&mut view, (8, 4), vec![click(0, 0), click(7, 3)]), [
+        true
+    ]);
+}
+
+#[test]
+fn modal_blocks_mouse() {
+    let mut view = LayeredView::new()
+        .layer(button("base"))
+        .modal(Placement::Center, button("ok"));
+
+    assert_eq!(send(&mut view, (8, 4), vec![click(0, 0), click(3, 1)]), [
+        true
+    ]);
+}
+
+#[test]
+fn layer_clamped_per_axis() {
+    let mut view = LayeredView::new()
+        .layer(text("wide layer"))
+        .layer_at((1, 0), DialogView::new(text("ab")));
+
+    // Widest and tallest layers
+    assert_eq!(view.desired_size(), (10, 4).into());
+
+    view.layout((8, 3).into());
+    assert_eq!(view.layer_rect(0), Some(Rect::new((0, 0), (8, 1))));
+    assert_eq!(view.layer_rect(1), Some(Rect::new((1, 0), (4, 3))));
+}
@@ -0,0 +1,88 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::Event,
+    executor,
+    layout::{
+        Alignment,
+        Constraint,
+    },
+    views::{
+        LinearView,
+        TextView,
+    },
+};
+
+fn text(s: &str) -> TextView<bool, Event> {
+    TextView::new(s)
+}
+
+#[test]
+fn fixed_and_fill() {
+    executor::test(
+        &mut LinearView::horizontal()
+            .child_with(Constraint::Fixed(3), text("abcdef"))
+            .child_with(Constraint::Fill(1), text("xy")),
+        vec![],
+        (8, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["abcxy   "]);
+        },
+    );
+}
+
+#[test]
+fn percentage_split() {
+    executor::test(
+        &mut LinearView::horizontal()
+            .child_with(Constraint::Percentage(30), text("111111111"))
+            .child_with(Constraint::Fill(1), text("2222")),
+        vec![],
+        (10, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["1112222   "]);
+        },
+    );
+}
+
+#[test]
+fn fill_pushes_to_bottom() {
+    executor::test(
+        &mut LinearView::vertical()
+            .child_with(Constraint::Fill(1), text("a"))
+            .child(text("status")),
+        vec![],
+        (6, 4).into(),
+        |lines| {
+            assert_eq!(lines, &["a     ", "      ", "      ", "status"]);
+        },
+    );
+}
+
+#[test]
+fn auto_keeps_earlier_children() {
+    executor::test(
+        &mut LinearView::horizontal()
+            .child(text("abcd"))
+            .child(text("efgh")),
+        vec![],
+        (6, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["abcdef"]);
+        },
+    );
+}
+
+#[test]
+fn cross_alignment() {
+    executor::test(
+        &mut LinearView::vertical()
+            .alignment(Alignment::Center)
+            .child(text("ab"))
+            .child(text("abcd")),
+        vec![],
+        (6, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["  ab  ", " abcd "]);
+        },
+    );
+}
@@ -0,0 +1,159 @@
+use pretty_assertions::assert_eq;
+use std::cell::RefCell;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        MouseEvent,
+        MouseEventKind,
+    },
+    executor,
+    printer::Printer,
+    view::View,
+    views::{
+        ListSource,
+        ListView,
+        ListViewMessage,
+    },
+};
+
+mod common;
+
+use common::click;
+
+/// Numbers up to `len` which remembers rendered indices
+struct Numbers {
+    len:      usize,
+    rendered: RefCell<Vec<usize>>,
+}
+
+impl Numbers {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            rendered: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl ListSource for Numbers {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn render_item(
+        &self,
+        index: usize,
+        printer: &mut Printer,
+    ) {
+        self.rendered.borrow_mut().push(index);
+        printer.print((0, 0), &index.to_string());
+    }
+}
+
+fn list() -> ListView<bool, Event, Vec<&'static str>> {
+    ListView::new(vec!["foo", "bar", "baz", "qux", "quux"])
+}
+
+#[test]
+fn list_render() {
+    executor::test_styled(&mut list(), Vec::<Event>::new(), (5, 3).into(), |lines| {
+        assert_eq!(lines, &[
+            "[reverse,yellow]foo  [/]",
+            "[white]bar[/]  ",
+            "[white]baz[/]  ",
+        ]);
+    });
+}
+
+#[test]
+fn list_renders_visible_items() {
+    let mut view = ListView::new(Numbers::new(10_000_000));
+    let events = vec![Event::from(KeyCode::End), Event::from(KeyCode::PageUp)];
+
+    executor::test(&mut view, events, (8, 3).into(), |lines| {
+        assert_eq!(lines, &["9999996 ", "9999997 ", "9999998 "]);
+    });
+
+    assert_eq!(view.selected(), Some(9_999_996));
+    assert_eq!(view.source().rendered.borrow().as_slice(), &[
+        0, 1, 2, 9_999_996, 9_999_997, 9_999_998
+    ]);
+}
+
+#[test]
+fn list_keyboard() {
+    let mut view = list();
+    let events: Vec<_> = vec![KeyCode::Down, KeyCode::Down, KeyCode::Down, KeyCode::Up]
+        .into_iter()
+        .map(Event::from)
+        .collect();
+
+    executor::test(&mut view, events, (5, 2).into(), |lines| {
+        assert_eq!(lines, &["baz  ", "qux  "]);
+    });
+
+    assert_eq!(view.selected(), Some(2));
+    assert_eq!(view.scroll(), 2);
+}
+
+#[test]
+fn list_keeps_selection_visible_on_layout() {
+    let mut state = false;
+    let mut view = list();
+
+    view.layout((5, 5).into());
+    view.on_event(&mut state, Event::from(KeyCode::End));
+    assert_eq!(view.scroll(), 0);
+
+    view.layout((5, 2).into());
+    assert_eq!(view.scroll(), 3);
+
+    view.layout((5, 4).into());
+    assert_eq!(view.scroll(), 1);
+}
+
+#[test]
+fn list_mouse() {
+    let mut state = false;
+    let mut view = list();
+    view.layout((5, 2).into());
+
+    let msgs: Vec<_> = vec![
+        click(0, 1),
+        click(0, 1),
+        Event::Mouse(MouseEvent::new(MouseEventKind::ScrollDown, (0, 0))),
+        click(0, 5),
+    ]
+    .into_iter()
+    .filter_map(|e| view.on_event(&mut state, e))
+    .collect();
+
+    assert_eq!(msgs, &[
+        ListViewMessage::IndexChanged,
+        ListViewMessage::Select,
+        ListViewMessage::IndexChanged,
+    ]);
+    assert!(state);
+    assert_eq!(view.selected(), Some(2));
+    assert_eq!(view.scroll(), 1);
+}
+
+#[test]
+fn list_update() {
+    let mut state = false;
+    let mut view = list();
+    view.layout((5, 2).into());
+    view.on_event(&mut state, Event::from(KeyCode::End));
+    state = false;
+
+    view.update(&mut state, |items| items.truncate(2));
+
+    assert!(state);
+    assert_eq!(view.selected(), Some(1));
+    assert_eq!(view.scroll(), 0);
+
+    view.update(&mut state, Vec::clear);
+    assert_eq!(view.selected(), None);
+    assert_eq!(view.on_event(&mut state, Event::from(KeyCode::Enter)), None);
+}
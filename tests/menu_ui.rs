@@ -0,0 +1,212 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+    },
+    executor,
+    view::View,
+    views::{
+        EditView,
+        LayeredView,
+        Menu,
+        MenuBarView,
+        MenuItem,
+    },
+};
+
+mod common;
+
+use common::{
+    click,
+    send,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Msg {
+    Open,
+    Save,
+    Recent(usize),
+    Quit,
+    Undo,
+    Edit,
+}
+
+fn menu_bar() -> MenuBarView<bool, Event, Msg, impl View<bool, Event, Message = Msg>> {
+    MenuBarView::new(EditView::new().map(|_, _, _| Msg::Edit))
+        .menu(
+            "File",
+            Menu::new()
+                .item(MenuItem::action("Open", Msg::Open).shortcut("ctrl-o"))
+                .item(MenuItem::action("Save", Msg::Save).enabled(false))
+                .separator()
+                .submenu(
+                    "Recent",
+                    Menu::new()
+                        .action("a.txt", Msg::Recent(0))
+                        .action("b.txt", Msg::Recent(1)),
+                )
+                .action("Quit", Msg::Quit),
+        )
+        .menu("Edit", Menu::new().action("Undo", Msg::Undo))
+}
+
+#[test]
+fn menu_closed() {
+    executor::test(
+        &mut menu_bar(),
+        vec![Event::from(KeyCode::Char('a'))],
+        (16, 2).into(),
+        |lines| {
+            assert_eq!(lines, &[" File  Edit     ", "a               "]);
+        },
+    );
+}
+
+#[test]
+fn menu_drop_down() {
+    executor::test_styled(
+        &mut menu_bar(),
+        vec![Event::from(KeyCode::F(10)), KeyCode::Down.into()],
+        (20, 8).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "[reverse,yellow] File [/][white] Edit         [/]",
+                "[white]┌────────────────┐[/]  ",
+                "[white]│ Open    ctrl-o │[/]  ",
+                "[white]│[/][fixed(8)] Save           [/][white]│[/]  ",
+                "[white]├────────────────┤[/]  ",
+                "[white]│[/][reverse,yellow] Recent       ▸ [/][white]│[/]  ",
+                "[white]│ Quit           │[/]  ",
+                "[white]└────────────────┘[/]  ",
+            ]);
+        },
+    );
+}
+
+#[test]
+fn menu_submenu_keyboard() {
+    let mut view = menu_bar();
+    let events: Vec<_> = vec![KeyCode::F(10), KeyCode::Down, KeyCode::Right, KeyCode::Down]
+        .into_iter()
+        .map(Event::from)
+        .collect();
+
+    executor::test(&mut view, events, (24, 8).into(), |lines| {
+        assert_eq!(lines, &[
+            " File  Edit             ",
+            "┌────────────────┐      ",
+            "│ Open    ctrl-o │      ",
+            "│ Save           │      ",
+            "├──────────────┌───────┐",
+            "│ Recent       │ a.txt │",
+            "│ Quit         │ b.txt │",
+            "└──────────────└───────┘",
+        ]);
+    });
+
+    assert_eq!(send(&mut view, (24, 8), vec![KeyCode::Enter.into()]), [
+        Msg::Recent(1)
+    ]);
+    assert!(!view.is_open());
+}
+
+#[test]
+fn menu_captures_events() {
+    let mut view = menu_bar();
+
+    assert_eq!(
+        send(&mut view, (24, 8), vec![
+            KeyCode::F(10).into(),
+            KeyCode::Char('x').into(),
+            KeyCode::Esc.into(),
+            KeyCode::Char('y').into(),
+        ]),
+        [Msg::Edit]
+    );
+}
+
+#[test]
+fn menu_esc_closes_submenus() {
+    let mut view = menu_bar();
+    let events: Vec<_> = vec![KeyCode::F(10), KeyCode::Down, KeyCode::Right, KeyCode::Esc]
+        .into_iter()
+        .map(Event::from)
+        .collect();
+
+    assert_eq!(send(&mut view, (24, 8), events), []);
+    assert!(!view.is_open());
+    assert_eq!(send(&mut view, (24, 8), vec![KeyCode::Char('z').into()]), [
+        Msg::Edit
+    ]);
+}
+
+#[test]
+fn menu_keyboard_switch_and_skip() {
+    let mut view = menu_bar();
+
+    // Up wraps from `Open` to `Quit`, Right on `Quit` goes to `Edit`
+    assert_eq!(
+        send(&mut view, (24, 8), vec![
+            KeyCode::F(10).into(),
+            KeyCode::Up.into(),
+            KeyCode::Enter.into(),
+        ]),
+        [Msg::Quit]
+    );
+    assert_eq!(
+        send(&mut view, (24, 8), vec![
+            KeyCode::F(10).into(),
+            KeyCode::Right.into(),
+            KeyCode::Enter.into(),
+        ]),
+        [Msg::Undo]
+    );
+}
+
+#[test]
+fn menu_mouse() {
+    let mut view = menu_bar();
+
+    // Disabled item and outside click
+    assert_eq!(
+        send(&mut view, (24, 8), vec![
+            click(1, 0),
+            click(3, 3),
+            click(22, 7)
+        ]),
+        []
+    );
+    assert!(!view.is_open());
+
+    assert_eq!(send(&mut view, (24, 8), vec![click(7, 0), click(8, 2)]), [
+        Msg::Undo
+    ]);
+    assert_eq!(
+        send(&mut view, (24, 8), vec![
+            click(1, 0),
+            click(3, 5),
+            click(17, 6)
+        ]),
+        [Msg::Recent(1)]
+    );
+}
+
+#[test]
+fn menu_over_layers() {
+    let layers = LayeredView::new().layer(EditView::new().map(|_, _, _| Msg::Edit));
+    let mut view = MenuBarView::new(layers).menu("Edit", Menu::new().action("Undo", Msg::Undo));
+    let events: Vec<_> = vec![KeyCode::Char('a'), KeyCode::F(10), KeyCode::Char('b')]
+        .into_iter()
+        .map(Event::from)
+        .collect();
+
+    executor::test(&mut view, events, (10, 4).into(), |lines| {
+        assert_eq!(lines, &[
+            " Edit     ",
+            "┌──────┐  ",
+            "│ Undo │  ",
+            "└──────┘  "
+        ]);
+    });
+}
@@ -0,0 +1,132 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+    },
+    executor,
+    layout::TextAlign,
+    orientation::Orientation,
+    view::View,
+    views::{
+        LinearView,
+        ParagraphView,
+        TextView,
+    },
+};
+
+fn paragraph(text: &str) -> ParagraphView<bool, Event> {
+    let mut view = ParagraphView::new();
+    view.append(text);
+    view
+}
+
+#[test]
+fn paragraph_word_wrap() {
+    executor::test(
+        &mut paragraph("hello world foo\nbar"),
+        vec![],
+        (8, 4).into(),
+        |lines| {
+            assert_eq!(lines, &["hello   ", "world   ", "foo     ", "bar     "]);
+        },
+    );
+}
+
+#[test]
+fn paragraph_cjk_and_long_word() {
+    executor::test(
+        &mut paragraph("가나다라마 abcdefghij"),
+        vec![],
+        (6, 4).into(),
+        |lines| {
+            assert_eq!(lines, &["가나다", "라마  ", "abcdef", "ghij  "]);
+        },
+    );
+}
+
+#[test]
+fn paragraph_no_wrap() {
+    executor::test(
+        &mut paragraph("hello world").wrap(false),
+        vec![],
+        (8, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["hello wo", "        "]);
+        },
+    );
+}
+
+#[test]
+fn paragraph_align() {
+    for (align, expected) in [
+        (TextAlign::Center, [" ab cd  ", "  efg   "]),
+        (TextAlign::Right, ["   ab cd", "     efg"]),
+        (TextAlign::Justify, ["ab    cd", "efg     "]),
+    ] {
+        executor::test(
+            &mut paragraph("ab cd efg").align(align),
+            vec![],
+            (8, 2).into(),
+            |lines| {
+                assert_eq!(lines, &expected);
+            },
+        );
+    }
+}
+
+#[test]
+fn paragraph_scroll_wrapped() {
+    executor::test(
+        &mut paragraph("aa bb cc dd").scrollable(Orientation::Vertical),
+        vec![Event::from(KeyCode::End)],
+        (3, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["cc│", "dd░"]);
+        },
+    );
+}
+
+#[test]
+fn paragraph_scroll_long() {
+    let text = (0..1000)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    executor::test(
+        &mut paragraph(&text).scrollable(Orientation::Vertical),
+        vec![Event::from(KeyCode::End), Event::from(KeyCode::Up)],
+        (4, 3).into(),
+        |lines| {
+            assert_eq!(lines, &["996│", "997░", "998│"]);
+        },
+    );
+}
+
+#[test]
+fn paragraph_wrapped_height_in_linear_view() {
+    let mut view = LinearView::vertical()
+        .child(paragraph("aa bb cc"))
+        .child(TextView::new("end"));
+
+    executor::test(&mut view, vec![], (4, 4).into(), |lines| {
+        assert_eq!(lines, &["aa  ", "bb  ", "cc  ", "end "]);
+    });
+}
+
+#[test]
+fn paragraph_ansi_lines() {
+    let mut view = ParagraphView::<bool, Event>::new();
+    view.append_ansi("\x1b[1;31merror\x1b[0m: \x1b[38;5;208mfoo\n");
+    view.append_ansi("\x1b[2Kbar\x1b[48;2;0;0;255m\n");
+    view.append_ansi("baz\x1b[0m");
+
+    executor::test_styled(&mut view, vec![], (10, 3).into(), |lines| {
+        assert_eq!(lines, &[
+            "[b,red]error[/][white]: [/][fixed(208)]foo[/]",
+            "[fixed(208)]bar[/]       ",
+            "[fixed(208),bg=#0000ff]baz[/]       ",
+        ]);
+    });
+}
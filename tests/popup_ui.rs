@@ -0,0 +1,152 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        MouseButton,
+        MouseEvent,
+        MouseEventKind,
+        MouseEventLike,
+    },
+    executor,
+    never::Never,
+    view::View,
+    views::{
+        ContextMenuView,
+        LayeredView,
+        Menu,
+        TextView,
+    },
+};
+
+mod common;
+
+use common::{
+    mouse,
+    send,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Msg {
+    Copy,
+    Paste,
+    Upper,
+}
+
+fn popup(pos: (u16, u16)) -> LayeredView<bool, Event, Msg> {
+    let menu = Menu::new()
+        .action("Copy", Msg::Copy)
+        .action("Paste", Msg::Paste)
+        .submenu("Case", Menu::new().action("Upper", Msg::Upper));
+    let mut view =
+        LayeredView::new().layer(TextView::new("text").map(|_, _, never: Never| match never {}));
+    view.add_popup(pos, ContextMenuView::new(menu));
+    view
+}
+
+#[test]
+fn mouse_buttons() {
+    let right = MouseEvent::new(MouseEventKind::Down(MouseButton::Right), (1, 2));
+    let middle = MouseEvent::new(MouseEventKind::Up(MouseButton::Middle), (1, 2));
+
+    assert_eq!(right.try_right_down(), Some((1, 2).into()));
+    assert_eq!(right.try_left_down(), None);
+    assert_eq!(right.try_any_down(), Some((1, 2).into()));
+    assert_eq!(middle.try_middle_up(), Some((1, 2).into()));
+    assert_eq!(middle.try_any_down(), None);
+}
+
+#[test]
+fn popup_at_position() {
+    executor::test(
+        &mut popup((2, 1)),
+        Vec::<Event>::new(),
+        (14, 6).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "text          ",
+                "  ┌──────────┐",
+                "  │ Copy     │",
+                "  │ Paste    │",
+                "  │ Case   ▸ │",
+                "  └──────────┘",
+            ]);
+        },
+    );
+}
+
+#[test]
+fn popup_clamped() {
+    executor::test(
+        &mut popup((10, 4)),
+        Vec::<Event>::new(),
+        (14, 6).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "text          ",
+                "  ┌──────────┐",
+                "  │ Copy     │",
+                "  │ Paste    │",
+                "  │ Case   ▸ │",
+                "  └──────────┘",
+            ]);
+        },
+    );
+}
+
+#[test]
+fn popup_select_closes() {
+    let mut view = popup((2, 1));
+
+    assert_eq!(
+        send(&mut view, (14, 6), vec![mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            4,
+            3
+        )]),
+        [Msg::Paste]
+    );
+    assert_eq!(view.len(), 1);
+}
+
+#[test]
+fn popup_esc_and_outside_click_close() {
+    let mut view = popup((2, 1));
+    assert_eq!(send(&mut view, (14, 6), vec![KeyCode::Esc.into()]), []);
+    assert_eq!(view.len(), 1);
+
+    let mut view = popup((2, 1));
+    // Release of the opening right click doesn't close it
+    assert_eq!(
+        send(&mut view, (14, 6), vec![
+            mouse(MouseEventKind::Up(MouseButton::Right), 0, 0),
+            KeyCode::Down.into(),
+        ]),
+        []
+    );
+    assert_eq!(view.len(), 2);
+    assert_eq!(
+        send(&mut view, (14, 6), vec![mouse(
+            MouseEventKind::Down(MouseButton::Right),
+            0,
+            0
+        )]),
+        []
+    );
+    assert_eq!(view.len(), 1);
+}
+
+#[test]
+fn popup_submenu_keyboard() {
+    let mut view = popup((2, 1));
+
+    assert_eq!(
+        send(&mut view, (14, 6), vec![
+            KeyCode::Up.into(),
+            KeyCode::Right.into(),
+            KeyCode::Enter.into(),
+        ]),
+        [Msg::Upper]
+    );
+    assert_eq!(view.len(), 1);
+}
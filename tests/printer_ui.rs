@@ -0,0 +1,47 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    executor,
+    printer::Printer,
+    rect::Rect,
+    vec2::Vec2,
+    view::View,
+};
+
+/// Draws a rect inside a bound which doesn't start at the origin
+struct Outline;
+
+impl<S, E> View<S, E> for Outline {
+    type Message = ();
+
+    fn render(
+        &self,
+        printer: &mut Printer,
+    ) {
+        printer.with_bound(Rect::new((1, 1), (4, 3)), |printer| printer.print_rect());
+    }
+
+    fn layout(
+        &mut self,
+        _size: Vec2,
+    ) {
+    }
+
+    fn desired_size(&self) -> Vec2 {
+        Vec2::new(5, 4)
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut S,
+        _event: E,
+    ) -> Option<()> {
+        None
+    }
+}
+
+#[test]
+fn print_rect_in_bound() {
+    executor::test(&mut Outline, Vec::<()>::new(), (6, 5).into(), |lines| {
+        assert_eq!(lines, &["      ", " ┌──┐ ", " │  │ ", " └──┘ ", "      "]);
+    });
+}
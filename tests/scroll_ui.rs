@@ -1,34 +1,47 @@
-use crossterm::event::{
-    Event,
-    KeyCode,
-    KeyEvent,
-    KeyModifiers,
-    MouseButton,
-    MouseEvent,
-};
 use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyModifier,
+        MouseButton,
+        MouseEvent,
+        MouseEventKind,
+    },
     executor,
+    focus::FocusDirection,
     orientation::Orientation,
+    rect::Rect,
     view::View,
+    view_wrappers::ScrollView,
     views::{
+        DialogView,
+        LayeredView,
         LinearView,
+        MenuBarView,
         TextView,
     },
 };
 
+mod common;
+
+use common::{
+    button,
+    mouse,
+    send,
+    text,
+};
+
 #[test]
 fn horizontal_scroll_mouse_down() {
     executor::test(
         &mut TextView::new("123456").scrollable(Orientation::Horizontal),
-        vec![Event::Mouse(MouseEvent::Down(
-            MouseButton::Left,
-            2,
-            1,
-            KeyModifiers::empty(),
+        vec![Event::Mouse(MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            (2, 1),
         ))],
         (4, 2).into(),
         |lines| {
-            assert_eq!(lines, &["2345", "──█─",]);
+            assert_eq!(lines, &["3456", "──░░",]);
         },
     )
 }
@@ -38,15 +51,13 @@ fn horizontal_scroll_mouse_down_linear_view() {
     executor::test(
         &mut LinearView::vertical()
             .child(TextView::new("123456").scrollable(Orientation::Horizontal)),
-        vec![Event::Mouse(MouseEvent::Down(
-            MouseButton::Left,
-            2,
-            1,
-            KeyModifiers::empty(),
+        vec![Event::Mouse(MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            (2, 1),
         ))],
         (4, 2).into(),
         |lines| {
-            assert_eq!(lines, &["2345", "──█─",]);
+            assert_eq!(lines, &["3456", "──░░",]);
         },
     )
 }
@@ -55,19 +66,10 @@ fn horizontal_scroll_mouse_down_linear_view() {
 fn horizontal_scroll_key_right() {
     executor::test(
         &mut TextView::new("123456").scrollable(Orientation::Horizontal),
-        vec![
-            Event::Key(KeyEvent {
-                code:      KeyCode::Right,
-                modifiers: KeyModifiers::empty(),
-            }),
-            Event::Key(KeyEvent {
-                code:      KeyCode::Right,
-                modifiers: KeyModifiers::empty(),
-            }),
-        ],
+        vec![Event::from(KeyCode::Right), Event::from(KeyCode::Right)],
         (4, 2).into(),
         |lines| {
-            assert_eq!(lines, &["2345", "──░─",]);
+            assert_eq!(lines, &["3456", "──░░",]);
         },
     )
 }
@@ -77,19 +79,10 @@ fn horizontal_scroll_key_right_linear_view() {
     executor::test(
         &mut LinearView::vertical()
             .child(TextView::new("123456").scrollable(Orientation::Horizontal)),
-        vec![
-            Event::Key(KeyEvent {
-                code:      KeyCode::Right,
-                modifiers: KeyModifiers::empty(),
-            }),
-            Event::Key(KeyEvent {
-                code:      KeyCode::Right,
-                modifiers: KeyModifiers::empty(),
-            }),
-        ],
+        vec![Event::from(KeyCode::Right), Event::from(KeyCode::Right)],
         (4, 2).into(),
         |lines| {
-            assert_eq!(lines, &["2345", "──░─",]);
+            assert_eq!(lines, &["3456", "──░░",]);
         },
     )
 }
@@ -100,19 +93,299 @@ fn horizontal_scroll_key_right_linear_view_two_childs() {
             .focus(1)
             .child(TextView::new("ABC"))
             .child(TextView::new("123456").scrollable(Orientation::Horizontal)),
+        vec![Event::from(KeyCode::Right), Event::from(KeyCode::Right)],
+        (4, 3).into(),
+        |lines| {
+            assert_eq!(lines, &["ABC ", "3456", "──░░",]);
+        },
+    )
+}
+
+fn grid() -> ScrollView<LinearView<bool, Event, bool>> {
+    let mut lines = LinearView::vertical();
+
+    for line in ["abcdef", "ghijkl", "mnopqr", "stuvwx"] {
+        lines.add_child(TextView::new(line).map(|_, _, _| false));
+    }
+
+    ScrollView::both(lines)
+}
+
+fn wheel(
+    kind: MouseEventKind,
+    shift: bool,
+) -> Event {
+    let mut me = MouseEvent::new(kind, (0, 0));
+    if shift {
+        me.modifiers = KeyModifier::Shift.into();
+    }
+    Event::Mouse(me)
+}
+
+#[test]
+fn scroll_both_axes() {
+    executor::test(&mut grid(), Vec::<Event>::new(), (4, 3).into(), |lines| {
+        assert_eq!(lines, &["abc░", "ghi│", "░──┘"]);
+    })
+}
+
+#[test]
+fn scroll_both_keys() {
+    executor::test(
+        &mut grid(),
         vec![
-            Event::Key(KeyEvent {
-                code:      KeyCode::Right,
-                modifiers: KeyModifiers::empty(),
-            }),
-            Event::Key(KeyEvent {
-                code:      KeyCode::Right,
-                modifiers: KeyModifiers::empty(),
-            }),
+            Event::from(KeyCode::Down),
+            Event::from(KeyCode::Right),
+            Event::from(KeyCode::Right),
         ],
         (4, 3).into(),
         |lines| {
-            assert_eq!(lines, &["ABC ", "2345", "──░─",]);
+            assert_eq!(lines, &["ijk░", "opq│", "─░─┘"]);
         },
     )
 }
+
+#[test]
+fn scroll_shift_wheel() {
+    executor::test(
+        &mut grid(),
+        vec![
+            wheel(MouseEventKind::ScrollDown, true),
+            wheel(MouseEventKind::ScrollDown, true),
+            wheel(MouseEventKind::ScrollDown, false),
+            wheel(MouseEventKind::ScrollDown, false),
+            wheel(MouseEventKind::ScrollUp, false),
+        ],
+        (4, 3).into(),
+        |lines| {
+            assert_eq!(lines, &["ijk░", "opq│", "─░─┘"]);
+        },
+    )
+}
+
+#[test]
+fn scroll_to_offset() {
+    let mut view = grid();
+    view.scroll_to((2, 1));
+
+    executor::test(&mut view, Vec::<Event>::new(), (4, 3).into(), |lines| {
+        assert_eq!(lines, &["ijk░", "opq│", "─░─┘"]);
+    });
+
+    // Clamped to content
+    view.scroll_to((10, 10));
+    executor::test(&mut view, Vec::<Event>::new(), (4, 3).into(), |lines| {
+        assert_eq!(lines, &["pqr│", "vwx░", "──░┘"]);
+    });
+}
+
+#[test]
+fn scroll_into_view_rect() {
+    let mut view = grid();
+    view.layout((4, 3).into());
+
+    view.scroll_into_view(Rect::new((4, 2), (2, 1)));
+    assert_eq!(view.offset(), (3, 1).into());
+
+    // Already visible
+    view.scroll_into_view(Rect::new((3, 1), (1, 1)));
+    assert_eq!(view.offset(), (3, 1).into());
+
+    view.scroll_into_view(Rect::new((0, 0), (1, 1)));
+    assert_eq!(view.offset(), (0, 0).into());
+}
+
+#[test]
+fn scroll_offset_clamped_on_layout() {
+    let mut view = grid();
+    view.layout((4, 3).into());
+
+    // Rect at the end of coordinate range doesn't overflow
+    view.scroll_into_view(Rect::new((u16::MAX - 1, u16::MAX - 1), (2, 2)));
+    assert_eq!(view.offset(), (u16::MAX - 3, u16::MAX - 2).into());
+
+    view.layout((4, 3).into());
+    assert_eq!(view.offset(), (3, 2).into());
+}
+
+#[test]
+fn scroll_mouse_to_content() {
+    let mut view = ScrollView::both(
+        LinearView::vertical()
+            .child(TextView::new("abcdef").map(|_, _, _| false))
+            .child(
+                turi::views::ButtonView::new("go", turi::views::ButtonDecoration::NoDecoration)
+                    .map(|_, _, _| true),
+            ),
+    );
+    let mut state = false;
+    view.layout((4, 2).into());
+    view.scroll_to((0, 1));
+
+    // Click at first row of view hits second row of content
+    assert_eq!(
+        view.on_event(
+            &mut state,
+            Event::Mouse(MouseEvent::new(
+                MouseEventKind::Down(MouseButton::Left),
+                (0, 0)
+            ))
+        ),
+        Some(true)
+    );
+}
+
+fn long_lines(count: usize) -> ScrollView<LinearView<bool, Event, bool>> {
+    let mut lines = LinearView::vertical();
+
+    for i in 0..count {
+        lines.add_child(TextView::new(i.to_string()).map(|_, _, _| false));
+    }
+
+    ScrollView::new(lines, Orientation::Vertical)
+}
+
+#[test]
+fn scroll_thumb_proportional() {
+    executor::test(
+        &mut long_lines(8),
+        vec![Event::from(KeyCode::PageDown)],
+        (2, 4).into(),
+        |lines| {
+            assert_eq!(lines, &["4│", "5│", "6░", "7░"]);
+        },
+    )
+}
+
+#[test]
+fn scroll_paging_keys() {
+    let mut view = long_lines(10);
+    let mut state = false;
+    view.layout((2, 4).into());
+
+    let mut send = |view: &mut ScrollView<_>, code: KeyCode| {
+        view.on_event(&mut state, Event::from(code));
+        view.offset().y
+    };
+
+    assert_eq!(send(&mut view, KeyCode::PageDown), 4);
+    assert_eq!(send(&mut view, KeyCode::PageDown), 6);
+    assert_eq!(send(&mut view, KeyCode::PageUp), 2);
+    assert_eq!(send(&mut view, KeyCode::End), 6);
+    assert_eq!(send(&mut view, KeyCode::Home), 0);
+}
+
+#[test]
+fn scroll_focused_content() {
+    let buttons = (0..10).fold(LinearView::vertical(), |view, i| {
+        view.child(button(&i.to_string()))
+    });
+    let mut view = ScrollView::new(buttons, Orientation::Vertical);
+    let tab = || Event::from(KeyCode::Tab);
+
+    // Tab scrolls focused button into view
+    view.scroll_to((0, 5));
+    assert_eq!(send(&mut view, (2, 3), vec![tab()]), []);
+    assert_eq!(view.offset().y, 0);
+
+    assert_eq!(send(&mut view, (2, 3), vec![tab(), tab(), tab(), tab()]), [
+    ]);
+    assert_eq!(view.offset().y, 2);
+
+    // Keys which buttons don't use scroll the view, Enter still goes to focused one
+    assert_eq!(
+        send(&mut view, (2, 3), vec![
+            Event::from(KeyCode::PageDown),
+            Event::from(KeyCode::Enter)
+        ]),
+        [true]
+    );
+    assert_eq!(view.offset().y, 5);
+}
+
+fn label_and_button() -> LinearView<bool, Event, bool> {
+    LinearView::vertical().child(text("a")).child(button("b"))
+}
+
+#[test]
+fn focus_rect_through_containers() {
+    let mut dialog = DialogView::new(label_and_button()).button("ok", |_| true);
+    dialog.take_focus(FocusDirection::Forward);
+    assert_eq!(send(&mut dialog, (6, 5), vec![]), []);
+    assert_eq!(dialog.focus_rect(), Some(Rect::new((1, 2), (1, 1))));
+    assert_eq!(
+        send(&mut dialog, (6, 5), vec![Event::from(KeyCode::Tab)]),
+        []
+    );
+    assert_eq!(dialog.focus_rect(), Some(Rect::new((1, 3), (4, 1))));
+
+    let mut layers = LayeredView::new()
+        .layer(text("x"))
+        .layer_at((2, 1), label_and_button());
+    layers.take_focus(FocusDirection::Forward);
+    layers.layout((10, 5).into());
+    assert_eq!(layers.focus_rect(), Some(Rect::new((2, 2), (1, 1))));
+
+    let mut menu_bar = MenuBarView::new(label_and_button());
+    menu_bar.take_focus(FocusDirection::Forward);
+    menu_bar.layout((10, 5).into());
+    assert_eq!(menu_bar.focus_rect(), Some(Rect::new((0, 2), (1, 1))));
+}
+
+#[test]
+fn scroll_track_click_and_drag() {
+    let mut view = long_lines(8);
+    let mut state = false;
+    view.layout((2, 4).into());
+
+    // Below thumb pages down
+    view.on_event(
+        &mut state,
+        mouse(MouseEventKind::Down(MouseButton::Left), 1, 3),
+    );
+    assert_eq!(view.offset().y, 4);
+
+    // Thumb is at 2..4 now, grab its second cell and drag it up
+    view.on_event(
+        &mut state,
+        mouse(MouseEventKind::Down(MouseButton::Left), 1, 3),
+    );
+    assert_eq!(view.offset().y, 4);
+    view.on_event(
+        &mut state,
+        mouse(MouseEventKind::Drag(MouseButton::Left), 1, 2),
+    );
+    assert_eq!(view.offset().y, 2);
+    view.on_event(
+        &mut state,
+        mouse(MouseEventKind::Up(MouseButton::Left), 1, 2),
+    );
+
+    // Above thumb pages up
+    view.on_event(
+        &mut state,
+        mouse(MouseEventKind::Down(MouseButton::Left), 1, 0),
+    );
+    assert_eq!(view.offset().y, 0);
+}
+
+#[test]
+fn scroll_auto_hide() {
+    executor::test(
+        &mut ScrollView::both(TextView::new("abc")).auto_hide(true),
+        Vec::<Event>::new(),
+        (4, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["abc ", "    "]);
+        },
+    );
+
+    executor::test(
+        &mut ScrollView::both(TextView::new("abcdef")).auto_hide(true),
+        Vec::<Event>::new(),
+        (4, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["abcd", "░░──"]);
+        },
+    );
+}
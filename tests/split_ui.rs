@@ -0,0 +1,168 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifier,
+        MouseButton,
+        MouseEventKind,
+    },
+    executor,
+    views::SplitView,
+};
+
+mod common;
+
+use common::{
+    button,
+    click,
+    mouse,
+    send,
+    text,
+};
+
+fn alt(code: KeyCode) -> Event {
+    Event::from(KeyEvent::new(code, KeyModifier::Alt))
+}
+
+#[test]
+fn split_horizontal() {
+    executor::test(
+        &mut SplitView::horizontal(text("left"), text("right")),
+        Vec::<Event>::new(),
+        (9, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["left│righ", "    │    "]);
+        },
+    );
+}
+
+#[test]
+fn split_nested() {
+    let mut view = SplitView::horizontal(
+        text("a"),
+        SplitView::vertical(text("b"), text("c")).position(0),
+    )
+    .position(2);
+
+    executor::test(&mut view, Vec::<Event>::new(), (6, 3).into(), |lines| {
+        assert_eq!(lines, &["a │───", "  │c  ", "  │   "]);
+    });
+}
+
+#[test]
+fn split_drag() {
+    let mut view = SplitView::horizontal(text("left"), text("right")).min_sizes(2, 3);
+
+    send(&mut view, (9, 3), vec![]);
+    assert_eq!(view.divider(), 4);
+
+    send(&mut view, (9, 3), vec![
+        mouse(MouseEventKind::Down(MouseButton::Left), 4, 1),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 2, 1),
+    ]);
+    assert_eq!(view.divider(), 2);
+
+    // Min size of first child
+    send(&mut view, (9, 3), vec![mouse(
+        MouseEventKind::Drag(MouseButton::Left),
+        0,
+        1,
+    )]);
+    assert_eq!(view.divider(), 2);
+
+    // Min size of second child
+    send(&mut view, (9, 3), vec![mouse(
+        MouseEventKind::Drag(MouseButton::Left),
+        8,
+        1,
+    )]);
+    assert_eq!(view.divider(), 5);
+
+    // Drag ends with release
+    send(&mut view, (9, 3), vec![
+        mouse(MouseEventKind::Up(MouseButton::Left), 8, 1),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 3, 1),
+    ]);
+    assert_eq!(view.divider(), 5);
+}
+
+#[test]
+fn split_keys() {
+    let mut view = SplitView::vertical(text("top"), text("bottom"));
+
+    send(&mut view, (9, 3), vec![alt(KeyCode::Down)]);
+    assert_eq!(view.divider(), 2);
+
+    send(&mut view, (9, 3), vec![
+        alt(KeyCode::Up),
+        alt(KeyCode::Up),
+        alt(KeyCode::Up),
+    ]);
+    assert_eq!(view.divider(), 0);
+
+    // Other orientation keys are ignored
+    send(&mut view, (9, 3), vec![alt(KeyCode::Right)]);
+    assert_eq!(view.divider(), 0);
+}
+
+#[test]
+fn split_mouse_to_children() {
+    let mut view = SplitView::horizontal(button("ab"), button("cd"));
+
+    assert_eq!(
+        send(&mut view, (9, 3), vec![
+            mouse(MouseEventKind::Down(MouseButton::Left), 1, 0),
+            // Divider isn't passed to children
+            mouse(MouseEventKind::Down(MouseButton::Left), 4, 0),
+            mouse(MouseEventKind::Up(MouseButton::Left), 4, 0),
+            mouse(MouseEventKind::Down(MouseButton::Left), 5, 0),
+        ]),
+        [true, true]
+    );
+    assert!(view.second().has_focus());
+    assert!(!view.first().has_focus());
+}
+
+#[test]
+fn split_click_keeps_focus() {
+    let mut view = SplitView::horizontal(button("a"), text("b"));
+
+    send(&mut view, (9, 3), vec![click(0, 0), click(6, 0)]);
+    assert!(view.first().has_focus());
+}
+
+#[test]
+fn split_nested_drag_released_outside() {
+    let mut view = SplitView::horizontal(
+        text("a"),
+        SplitView::vertical(text("b"), text("c")).position(0),
+    )
+    .position(2);
+    let events = vec![
+        mouse(MouseEventKind::Down(MouseButton::Left), 4, 0),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 4, 1),
+        mouse(MouseEventKind::Up(MouseButton::Left), 0, 2),
+        mouse(MouseEventKind::Drag(MouseButton::Left), 4, 2),
+    ];
+
+    executor::test(&mut view, events, (6, 3).into(), |lines| {
+        assert_eq!(lines, &["a │b  ", "  │───", "  │c  "]);
+    });
+}
+
+#[test]
+fn split_keys_to_focused_child_first() {
+    let mut view =
+        SplitView::horizontal(button("a"), SplitView::horizontal(button("b"), button("c")));
+
+    send(&mut view, (9, 1), vec![click(5, 0)]);
+    assert_eq!(view.divider(), 4);
+
+    // Inner split moves its divider, outer one moves when inner can't
+    send(&mut view, (9, 1), vec![alt(KeyCode::Left)]);
+    assert_eq!(view.divider(), 4);
+    send(&mut view, (9, 1), vec![alt(KeyCode::Left)]);
+    assert_eq!(view.divider(), 3);
+}
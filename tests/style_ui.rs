@@ -0,0 +1,142 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+    },
+    executor,
+    style::{
+        AnsiColor,
+        Color,
+        Effect,
+        Style,
+    },
+    styled_text::StyledText,
+    view::View,
+    views::{
+        ButtonDecoration,
+        ButtonView,
+        DialogView,
+        ParagraphView,
+        SelectView,
+        TextView,
+    },
+};
+
+fn red_bold() -> Style {
+    Style::default()
+        .fg(Color::Ansi(Some(AnsiColor::Red)))
+        .effects(Effect::Bold)
+}
+
+#[test]
+fn select_view_highlight_selected() {
+    executor::test_styled(
+        &mut SelectView::with_items(vec![("123".into(), 1), ("456".into(), 2)]),
+        vec![Event::from(KeyCode::Down)],
+        (4, 2).into(),
+        |lines| {
+            assert_eq!(lines, &["[white]123[/] ", "[reverse,yellow]456[/] ",]);
+        },
+    )
+}
+
+#[test]
+fn dialog_view_title_style() {
+    executor::test_styled(
+        &mut DialogView::new(TextView::new("ABC").map(|_, _, _| false)).title("T"),
+        Vec::<Event>::new(),
+        (5, 4).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "[cyan]T[/][white]───┐[/]",
+                "[white]│ABC│[/]",
+                "[white]│[/]   [white]│[/]",
+                "[white]└───┘[/]",
+            ]);
+        },
+    )
+}
+
+#[test]
+fn text_view_spans() {
+    let text = StyledText::plain("ab")
+        .span("cd", red_bold())
+        .span("가", Style::default());
+
+    executor::test_styled(
+        &mut TextView::<bool, Event>::styled(text),
+        vec![],
+        (6, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["[white]ab[/][b,red]cd[/][white]가[/]"]);
+        },
+    )
+}
+
+#[test]
+fn text_view_spans_truncated() {
+    let text = StyledText::plain("ab").span("cd가", red_bold());
+
+    executor::test_styled(
+        &mut TextView::<bool, Event>::styled(text),
+        vec![],
+        (5, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["[white]ab[/][b,red]cd[/] "]);
+        },
+    )
+}
+
+#[test]
+fn button_view_styled_label() {
+    executor::test_styled(
+        &mut ButtonView::<bool, Event>::styled(
+            StyledText::plain("o").span("k", red_bold()),
+            ButtonDecoration::Angle,
+        ),
+        vec![],
+        (4, 1).into(),
+        |lines| {
+            // Focused button is highlighted
+            assert_eq!(lines, &[
+                "[reverse,yellow]<o[/][b,reverse,red]k[/][reverse,yellow]>[/]"
+            ]);
+        },
+    )
+}
+
+#[test]
+fn paragraph_view_wrap_spans() {
+    let mut view = ParagraphView::<bool, Event>::new();
+    view.append(StyledText::plain("ab ").span("cd ef", red_bold()));
+
+    executor::test_styled(&mut view, vec![], (5, 2).into(), |lines| {
+        assert_eq!(lines, &["[white]ab [/][b,red]cd[/]", "[b,red]ef[/]   "]);
+    })
+}
+
+#[test]
+fn text_view_markup() {
+    executor::test_styled(
+        &mut TextView::<bool, Event>::markup("[b,red]cd[/bold] [reverse]x[/]").unwrap(),
+        vec![],
+        (4, 1).into(),
+        |lines| {
+            assert_eq!(lines, &["[b,red]cd[/][white] [/][reverse,white]x[/]"]);
+        },
+    );
+
+    assert!(TextView::<bool, Event>::markup("[b]x[/i]").is_err());
+}
+
+#[test]
+fn paragraph_view_markup() {
+    let mut view = ParagraphView::<bool, Event>::new();
+    view.append_markup("ab\n[b,red]cd[/]").unwrap();
+    assert!(view.append_markup("[nope]x").is_err());
+
+    executor::test_styled(&mut view, vec![], (2, 2).into(), |lines| {
+        assert_eq!(lines, &["[white]ab[/]", "[b,red]cd[/]"]);
+    })
+}
@@ -0,0 +1,123 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifier,
+    },
+    executor,
+    rect::Rect,
+    view::View,
+    views::{
+        EditView,
+        LinearView,
+        TabView,
+        TextView,
+    },
+};
+
+mod common;
+
+use common::click;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Msg {
+    Tab(usize),
+    Edit,
+}
+
+fn edit() -> impl View<bool, Event, Message = Msg> {
+    EditView::new().map(|_, _, _| Msg::Edit)
+}
+
+fn tabs() -> TabView<bool, Event, Msg> {
+    TabView::new()
+        .tab("one", edit())
+        .tab("two", edit())
+        .on_change(|_, index| Msg::Tab(index))
+}
+
+#[test]
+fn tab_bar() {
+    let mut view = TabView::new()
+        .tab("a", TextView::new("first"))
+        .tab("bb", TextView::new("second"));
+
+    executor::test_styled(&mut view, Vec::<Event>::new(), (9, 2).into(), |lines| {
+        assert_eq!(lines, &[
+            "[reverse,black] a [/][white]│ bb [/] ",
+            "[white]first[/]    ",
+        ]);
+    });
+}
+
+#[test]
+fn tab_click_switches() {
+    let mut view = tabs();
+    let events = vec![
+        click(6, 0),
+        Event::from(KeyCode::Char('x')),
+        Event::from(KeyEvent::new(KeyCode::PageDown, KeyModifier::Control)),
+        Event::from(KeyCode::Char('y')),
+    ];
+
+    executor::test(&mut view, events, (10, 2).into(), |lines| {
+        assert_eq!(lines, &[" one │ two", "y         "]);
+    });
+
+    assert_eq!(view.active(), 0);
+}
+
+#[test]
+fn tab_change_message() {
+    let mut view = tabs();
+    let mut state = false;
+
+    view.layout((10, 2).into());
+    view.take_focus(turi::focus::FocusDirection::Forward);
+
+    assert_eq!(view.on_event(&mut state, click(3, 0)), None);
+    assert_eq!(view.on_event(&mut state, click(5, 0)), None);
+    assert_eq!(view.on_event(&mut state, click(6, 0)), Some(Msg::Tab(1)));
+    assert!(view.has_focus());
+    assert_eq!(
+        view.on_event(&mut state, KeyCode::Char('a').into()),
+        Some(Msg::Edit)
+    );
+    assert_eq!(
+        view.on_event(
+            &mut state,
+            KeyEvent::new(KeyCode::PageUp, KeyModifier::Control).into()
+        ),
+        Some(Msg::Tab(0))
+    );
+}
+
+#[test]
+fn tab_custom_keys() {
+    let mut view = tabs().keys(KeyCode::F(1).into(), KeyCode::F(2).into());
+    let mut state = false;
+
+    view.layout((10, 2).into());
+    assert_eq!(
+        view.on_event(&mut state, KeyCode::F(2).into()),
+        Some(Msg::Tab(1))
+    );
+    assert_eq!(
+        view.on_event(&mut state, KeyCode::F(2).into()),
+        Some(Msg::Tab(0))
+    );
+}
+
+#[test]
+fn tab_focus_rect_under_bar() {
+    let page = LinearView::vertical()
+        .child(TextView::new("a").map(|_, _, _| Msg::Edit))
+        .child(edit());
+    let mut view = TabView::new().tab("one", page);
+
+    view.take_focus(turi::focus::FocusDirection::Forward);
+    view.layout((10, 3).into());
+    assert_eq!(view.focus_rect(), Some(Rect::new((0, 2), (1, 1))));
+}
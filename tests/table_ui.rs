@@ -0,0 +1,188 @@
+use pretty_assertions::assert_eq;
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+};
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+    },
+    executor,
+    layout::{
+        Constraint,
+        TextAlign,
+    },
+    view::View,
+    views::{
+        SortOrder,
+        TableColumn,
+        TableRow,
+        TableView,
+        TableViewMessage,
+    },
+};
+
+mod common;
+
+use common::click;
+
+struct Item {
+    name:  &'static str,
+    count: usize,
+}
+
+impl TableRow for Item {
+    fn cell(
+        &self,
+        column: usize,
+    ) -> Cow<'_, str> {
+        match column {
+            0 => self.name.into(),
+            _ => self.count.to_string().into(),
+        }
+    }
+
+    fn compare(
+        &self,
+        other: &Self,
+        column: usize,
+    ) -> Ordering {
+        match column {
+            0 => self.name.cmp(other.name),
+            _ => self.count.cmp(&other.count),
+        }
+    }
+}
+
+fn table() -> TableView<bool, Event, Item> {
+    TableView::new()
+        .column(TableColumn::new("name").constraint(Constraint::Fill(1)))
+        .column(TableColumn::new("n").align(TextAlign::Right))
+        .rows(vec![
+            Item {
+                name:  "foo",
+                count: 10,
+            },
+            Item {
+                name:  "bar",
+                count: 2,
+            },
+            Item {
+                name:  "baz",
+                count: 300,
+            },
+        ])
+}
+
+#[test]
+fn table_render() {
+    executor::test_styled(&mut table(), Vec::<Event>::new(), (10, 5).into(), |lines| {
+        assert_eq!(lines, &[
+            "[cyan]name[/]     [cyan]n[/]",
+            "[reverse,yellow]foo     10[/]",
+            "[white]bar[/]      [white]2[/]",
+            "[white]baz[/]    [white]300[/]",
+            "          ",
+        ]);
+    });
+}
+
+#[test]
+fn table_keyboard() {
+    let mut view = table();
+    let events: Vec<_> = vec![KeyCode::Down, KeyCode::Down, KeyCode::Up]
+        .into_iter()
+        .map(Event::from)
+        .collect();
+
+    executor::test(&mut view, events, (10, 4).into(), |lines| {
+        assert_eq!(lines, &[
+            "name     n",
+            "foo     10",
+            "bar      2",
+            "baz    300"
+        ]);
+    });
+
+    assert_eq!(view.selected_row().unwrap().name, "bar");
+}
+
+#[test]
+fn table_click_sort() {
+    let mut view = table();
+
+    // Select `baz`, sort by count twice
+    executor::test(
+        &mut view,
+        vec![click(0, 3), click(8, 0), click(8, 0)],
+        (10, 4).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "name    n▼",
+                "baz    300",
+                "foo     10",
+                "bar      2"
+            ]);
+        },
+    );
+
+    assert_eq!(view.sorted_by(), Some((1, SortOrder::Descending)));
+    assert_eq!(view.selected_row().unwrap().name, "baz");
+    assert_eq!(view.selected_index(), Some(2));
+}
+
+#[test]
+fn table_click_selected_row() {
+    let mut view = table();
+    let mut state = false;
+
+    view.layout((10, 4).into());
+    assert_eq!(
+        view.on_event(&mut state, click(0, 2)),
+        Some(TableViewMessage::IndexChanged)
+    );
+    assert_eq!(
+        view.on_event(&mut state, click(0, 2)),
+        Some(TableViewMessage::Select)
+    );
+}
+
+#[test]
+fn table_push_row_sorted() {
+    let mut view = table();
+    view.sort(0, SortOrder::Ascending);
+    view.push_row(Item {
+        name:  "baa",
+        count: 1,
+    });
+
+    executor::test(&mut view, Vec::<Event>::new(), (10, 5).into(), |lines| {
+        assert_eq!(lines, &[
+            "name▲    n",
+            "baa      1",
+            "bar      2",
+            "baz    300",
+            "foo     10",
+        ]);
+    });
+}
+
+#[test]
+fn table_large_scroll() {
+    let rows = (0..100_000)
+        .map(|count| Item { name: "x", count })
+        .collect();
+    let mut view = TableView::new()
+        .column(TableColumn::new("name"))
+        .column(TableColumn::new("count").align(TextAlign::Right))
+        .rows(rows);
+    let events: Vec<_> = vec![KeyCode::End, KeyCode::Up, KeyCode::Up, KeyCode::Up]
+        .into_iter()
+        .map(Event::from)
+        .collect();
+
+    executor::test(&mut view, events, (12, 3).into(), |lines| {
+        assert_eq!(lines, &["name   count", "x      99996", "x      99997"]);
+    });
+}
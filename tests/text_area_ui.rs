@@ -0,0 +1,94 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+        KeyEvent,
+        KeyModifier,
+    },
+    executor,
+    views::TextAreaView,
+};
+
+fn chars(text: &str) -> impl Iterator<Item = Event> + '_ {
+    text.chars().map(|ch| Event::from(KeyCode::Char(ch)))
+}
+
+#[test]
+fn text_area_type_lines() {
+    let events: Vec<_> = chars("ab")
+        .chain(Some(Event::from(KeyCode::Enter)))
+        .chain(chars("cd"))
+        .collect();
+
+    executor::test_styled(&mut TextAreaView::new(), events, (4, 2).into(), |lines| {
+        assert_eq!(lines, &[
+            "[white]ab[/]  ",
+            "[white]cd[/][reverse,white] [/] "
+        ]);
+    });
+}
+
+#[test]
+fn text_area_insert_middle() {
+    let events: Vec<_> = chars("ad")
+        .chain(vec![Event::from(KeyCode::Left)])
+        .chain(chars("bc"))
+        .chain(vec![
+            Event::from(KeyCode::Home),
+            Event::from(KeyCode::Delete),
+            Event::from(KeyCode::End),
+            Event::from(KeyCode::Backspace),
+        ])
+        .collect();
+
+    executor::test(&mut TextAreaView::new(), events, (4, 1).into(), |lines| {
+        assert_eq!(lines, &["bc  "]);
+    });
+}
+
+#[test]
+fn text_area_horizontal_scroll_wide() {
+    let events: Vec<_> = chars("ab")
+        .chain(Some(Event::from(KeyCode::Enter)))
+        .chain(chars("가나다라"))
+        .collect();
+
+    executor::test(&mut TextAreaView::new(), events, (5, 2).into(), |lines| {
+        assert_eq!(lines, &["     ", "다라 "]);
+    });
+}
+
+#[test]
+fn text_area_scroll_cut_wide_char() {
+    let events: Vec<_> = chars("가나다")
+        .chain(vec![
+            Event::from(KeyCode::Left),
+            Event::from(KeyCode::Left),
+            Event::from(KeyCode::Right),
+        ])
+        .chain(chars("a"))
+        .chain(Some(Event::from(KeyCode::End)))
+        .collect();
+
+    executor::test(&mut TextAreaView::new(), events, (5, 1).into(), |lines| {
+        assert_eq!(lines, &[" a다 "]);
+    });
+}
+
+#[test]
+fn text_area_join_lines() {
+    let events: Vec<_> = chars("ab")
+        .chain(Some(Event::from(KeyCode::Enter)))
+        .chain(chars("cd"))
+        .chain(vec![
+            Event::from(KeyCode::Home),
+            Event::from(KeyCode::Backspace),
+            Event::from(KeyEvent::new(KeyCode::Home, KeyModifier::Control)),
+        ])
+        .collect();
+
+    executor::test(&mut TextAreaView::new(), events, (5, 2).into(), |lines| {
+        assert_eq!(lines, &["abcd ", "     "]);
+    });
+}
@@ -0,0 +1,191 @@
+use pretty_assertions::assert_eq;
+use turi::{
+    event::{
+        Event,
+        KeyCode,
+    },
+    executor,
+    view::View,
+    views::{
+        TreeView,
+        TreeViewMessage,
+    },
+};
+
+mod common;
+
+use common::click;
+
+fn tree() -> TreeView<bool, Event, u32> {
+    let mut view = TreeView::new();
+    let src = view.insert(None, "src", 0);
+    let views = view.insert(Some(src), "views", 1);
+    view.insert(Some(views), "a.rs", 2);
+    view.insert(Some(views), "b.rs", 3);
+    view.insert(Some(src), "lib.rs", 4);
+    view.insert(None, "README", 5);
+    view
+}
+
+fn keys(codes: Vec<KeyCode>) -> Vec<Event> {
+    codes.into_iter().map(Event::from).collect()
+}
+
+#[test]
+fn tree_collapsed() {
+    executor::test(&mut tree(), Vec::<Event>::new(), (10, 3).into(), |lines| {
+        assert_eq!(lines, &["▸ src     ", "  README  ", "          "]);
+    });
+}
+
+#[test]
+fn tree_expand_guides() {
+    let mut view = tree();
+
+    executor::test(
+        &mut view,
+        keys(vec![KeyCode::Right, KeyCode::Right, KeyCode::Right]),
+        (12, 6).into(),
+        |lines| {
+            assert_eq!(lines, &[
+                "▾ src       ",
+                "├─▾ views   ",
+                "│ ├── a.rs  ",
+                "│ └── b.rs  ",
+                "└── lib.rs  ",
+                "  README    ",
+            ]);
+        },
+    );
+
+    assert_eq!(view.selected_val(), Some(&1));
+}
+
+#[test]
+fn tree_left_collapses_and_selects_parent() {
+    let mut view = tree();
+    let events = keys(vec![
+        KeyCode::Right,
+        KeyCode::Right,
+        KeyCode::Right,
+        KeyCode::Down,
+        KeyCode::Left,
+        KeyCode::Left,
+    ]);
+
+    executor::test(&mut view, events, (12, 4).into(), |lines| {
+        assert_eq!(lines, &[
+            "▾ src       ",
+            "├─▸ views   ",
+            "└── lib.rs  ",
+            "  README    ",
+        ]);
+    });
+
+    assert_eq!(view.selected_val(), Some(&1));
+}
+
+#[test]
+fn tree_insert_into_expanded() {
+    let mut view = tree();
+    let mut state = false;
+
+    view.select_node(&mut state, 2);
+    view.select_node(&mut state, 4);
+    view.insert(Some(1), "c.rs", 6);
+    view.insert(Some(0), "main.rs", 7);
+    view.insert(Some(2), "x", 8);
+
+    executor::test(&mut view, Vec::<Event>::new(), (12, 8).into(), |lines| {
+        assert_eq!(lines, &[
+            "▾ src       ",
+            "├─▾ views   ",
+            "│ ├─▸ a.rs  ",
+            "│ ├── b.rs  ",
+            "│ └── c.rs  ",
+            "├── lib.rs  ",
+            "└── main.rs ",
+            "  README    ",
+        ]);
+    });
+
+    assert_eq!(view.selected_val(), Some(&4));
+    assert_eq!(view.desired_size(), (11, 8).into());
+}
+
+#[test]
+fn tree_mouse_toggle() {
+    let mut view = tree();
+    let mut state = false;
+
+    view.layout((10, 5).into());
+    assert_eq!(
+        view.on_event(&mut state, click(0, 0)),
+        Some(TreeViewMessage::Expanded)
+    );
+    assert_eq!(
+        view.on_event(&mut state, click(0, 2)),
+        Some(TreeViewMessage::IndexChanged)
+    );
+    assert_eq!(
+        view.on_event(&mut state, click(0, 2)),
+        Some(TreeViewMessage::Select)
+    );
+    assert_eq!(view.selected_val(), Some(&4));
+    assert_eq!(
+        view.on_event(&mut state, click(0, 0)),
+        Some(TreeViewMessage::Collapsed)
+    );
+    assert_eq!(view.selected_val(), Some(&0));
+}
+
+#[test]
+fn tree_lazy_children() {
+    let mut view = TreeView::new().loader(|depth: &u32| {
+        if *depth < 2 {
+            vec![(format!("d{}", depth + 1), depth + 1, true)]
+        } else {
+            Vec::new()
+        }
+    });
+    let root = view.insert_lazy(None, "d0", 0);
+
+    assert!(view.children(root).is_empty());
+
+    executor::test(
+        &mut view,
+        keys(vec![
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::Right,
+        ]),
+        (10, 3).into(),
+        |lines| {
+            assert_eq!(lines, &["▾ d0      ", "└─▾ d1    ", "  └── d2  "]);
+        },
+    );
+}
+
+#[test]
+fn tree_mouse_click_empty_lazy() {
+    let mut view = TreeView::new().loader(|_: &u32| Vec::new());
+    view.insert(None, "a", 0);
+    view.insert_lazy(None, "b", 1);
+    let mut state = false;
+
+    view.layout((10, 2).into());
+    assert_eq!(
+        view.on_event(&mut state, click(0, 1)),
+        Some(TreeViewMessage::IndexChanged)
+    );
+    assert_eq!(
+        view.on_event(&mut state, click(0, 1)),
+        Some(TreeViewMessage::Select)
+    );
+
+    executor::test(&mut view, Vec::<Event>::new(), (10, 2).into(), |lines| {
+        assert_eq!(lines, &["  a       ", "  b       "]);
+    });
+}